@@ -53,4 +53,52 @@ fn main() {
     bindings
         .write_to_file(recent_path.join("src/cint/libcint.rs"))
         .expect("Couldn't write bindings!");
+
+    #[cfg(feature = "basis-library")]
+    basis_library::embed(&recent_path);
+}
+
+/// Common basis sets shipped in `data/`, one Basis Set Exchange JSON document per basis set
+/// (`bse get-basis <name> json`, ECP-only elements left out), deflated into `OUT_DIR` for
+/// `cint::library`. No Basis Set Exchange checkout is needed.
+#[cfg(feature = "basis-library")]
+mod basis_library {
+    use miniz_oxide::deflate::compress_to_vec;
+    use std::{fs, path::Path};
+
+    /// Lower case, as the Basis Set Exchange names them.
+    const BASIS_NAMES: [&str; 13] = [
+        "sto-3g",
+        "6-31g*",
+        "cc-pvdz",
+        "cc-pvtz",
+        "def2-svp",
+        "def2-tzvp",
+        "cc-pvdz-jkfit",
+        "cc-pvtz-jkfit",
+        "def2-universal-jkfit",
+        "cc-pvdz-rifit",
+        "cc-pvtz-rifit",
+        "def2-svp-rifit",
+        "def2-tzvp-rifit",
+    ];
+
+    pub fn embed(crate_path: &Path) {
+        let data = crate_path.join("data");
+        let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+
+        let mut library = String::from("static LIBRARY: &[(&str, &[u8])] = &[\n");
+        for (i, name) in BASIS_NAMES.iter().enumerate() {
+            // `*` spelled `_st_`, as in the Basis Set Exchange file names
+            let json_path = data.join(format!("{}.json", name.replace('*', "_st_")));
+            println!("cargo:rerun-if-changed={}", json_path.display());
+            let json = fs::read(&json_path)
+                .unwrap_or_else(|why| panic!("cannot read {}: {}", json_path.display(), why));
+            let path = out_dir.join(format!("basis_{}.json.deflate", i));
+            fs::write(&path, compress_to_vec(&json, 9)).unwrap();
+            library += &format!("    ({:?}, include_bytes!({:?})),\n", name, path);
+        }
+        library += "];\n";
+        fs::write(out_dir.join("basis_library.rs"), library).unwrap();
+    }
 }
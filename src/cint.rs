@@ -1,7 +1,18 @@
+pub mod auxbasis;
+pub mod basis_format;
+pub mod basis_spec;
 pub mod cdata;
+pub mod error;
 pub mod intor;
+pub mod kind;
 pub mod libcint;
+#[cfg(feature = "basis-library")]
+pub mod library;
+pub mod npy;
+pub mod nucmod;
+pub mod optimizer;
 pub mod rawdata;
+pub mod registry;
 
 pub type AtomIndex = usize;
 pub type NAtom = usize;
@@ -11,3 +22,12 @@ pub type NBasis = usize;
 
 pub const NUC_POINT: i32 = 1;
 pub const NUC_GAUSS: i32 = 2;
+/// libcint's `FRAC_CHARGE_NUC`, typed like the other nuclear models.
+pub const NUC_FRAC_CHARGE: i32 = libcint::FRAC_CHARGE_NUC as i32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CintType {
+    Cartesian,
+    Spheric,
+    Spinor,
+}
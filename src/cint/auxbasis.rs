@@ -0,0 +1,93 @@
+//! Even-tempered auxiliary basis sets generated from orbital basis sets, after PySCF's
+//! `aug_etb`. The exponent range of every product angular momentum is estimated from the
+//! orbital primitives and filled with uncontracted shells whose exponents differ by `beta`.
+
+use super::{
+    basis_format::BasisMap,
+    error::CintError,
+    rawdata::{Normalization, CGTO},
+};
+
+/// Ratio of consecutive auxiliary exponents, PySCF's `ETB_BETA`.
+pub const ETB_BETA: f64 = 2.0;
+
+/// Highest angular momentum occupied in the ground state of element `charge`.
+fn occupied_angl(charge: u8) -> usize {
+    match charge {
+        0..=4 => 0,
+        5..=20 => 1,
+        21..=57 => 2,
+        _ => 3,
+    }
+}
+
+/// Auxiliary shells for the orbital `shells` of element `charge`, scaled by `norm`. Orbital
+/// shells above the occupied angular momenta plus one, and primitives of zero coefficient,
+/// do not widen the exponent ranges. `beta` must be greater than 1.
+pub fn aug_etb(
+    charge: u8,
+    shells: &[CGTO],
+    beta: f64,
+    norm: Normalization,
+) -> Result<Vec<CGTO>, CintError> {
+    if beta.is_nan() || beta <= 1.0 {
+        return Err(CintError::EtbBeta { beta });
+    }
+    // smallest and largest exponent of every orbital angular momentum
+    let mut range: Vec<Option<(f64, f64)>> = Vec::new();
+    for cgto in shells {
+        for (ictr, row) in cgto.coeff.iter().enumerate() {
+            let angl = cgto.angl_of_coeff(ictr) as usize;
+            if angl > occupied_angl(charge) + 1 {
+                continue;
+            }
+            if range.len() <= angl {
+                range.resize(angl + 1, None);
+            }
+            for (exp, _) in cgto.exp.iter().zip(row).filter(|(_, c)| **c != 0.0) {
+                let (emin, emax) = range[angl].get_or_insert((*exp, *exp));
+                *emin = emin.min(*exp);
+                *emax = emax.max(*exp);
+            }
+        }
+    }
+
+    let mut aux = Vec::new();
+    for angl in 0..(2 * range.len()).saturating_sub(1) {
+        // geometric means over the orbital pairs of total angular momentum `angl`, doubled
+        // for the product of two primitives on one centre
+        let pairs = (0..=angl).filter_map(|li| match (range.get(li), range.get(angl - li)) {
+            (Some(Some((emin_i, emax_i))), Some(Some((emin_j, emax_j)))) => Some((
+                2.0 * (emin_i * emin_j).sqrt(),
+                2.0 * (emax_i * emax_j).sqrt(),
+            )),
+            _ => None,
+        });
+        let Some((emin, emax)) = pairs.reduce(|(a, b), (c, d)| (a.min(c), b.max(d))) else {
+            continue;
+        };
+        let n = (((emax + emin) / emin).ln() / beta.ln()).ceil() as i32;
+        aux.extend((0..n).rev().map(|i| {
+            CGTO {
+                kappa_of: 0,
+                angl: vec![angl as u8],
+                exp: vec![emin * beta.powi(i)],
+                coeff: vec![vec![1.0]],
+            }
+            .with_norm(norm)
+        }));
+    }
+    Ok(aux)
+}
+
+/// `aug_etb` for every element of `basis`.
+pub fn aug_etb_basis(
+    basis: &BasisMap,
+    beta: f64,
+    norm: Normalization,
+) -> Result<BasisMap, CintError> {
+    basis
+        .iter()
+        .map(|(charge, shells)| Ok((*charge, aug_etb(*charge, shells, beta, norm)?)))
+        .collect()
+}
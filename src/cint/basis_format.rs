@@ -0,0 +1,734 @@
+//! Readers and writers of basis set files in the formats of the Basis Set Exchange. Every
+//! reader gives the contracted shells of each element it lists, keyed by nuclear charge, ready
+//! for `CintAtomGroup::from_xyz`. ECP sections are not read.
+
+use core::io::{bse::JsonBasis, PERIODIC_TABLE};
+use serde_json::json;
+use std::{collections::BTreeMap, fs, path::Path};
+
+use super::{
+    error::CintError,
+    rawdata::{GetCGTO, Normalization, CGTO},
+};
+
+pub type BasisMap = BTreeMap<u8, Vec<CGTO>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisFormat {
+    BseJson,
+    NWChem,
+    Gaussian94,
+    Molpro,
+    Genbas,
+    Turbomole,
+    Orca,
+}
+
+#[rustfmt::skip]
+const ELEMENT_NAMES: [&str; 118] = [
+    "hydrogen", "helium", "lithium", "beryllium", "boron", "carbon", "nitrogen", "oxygen",
+    "fluorine", "neon", "sodium", "magnesium", "aluminium", "silicon", "phosphorus", "sulfur",
+    "chlorine", "argon", "potassium", "calcium", "scandium", "titanium", "vanadium", "chromium",
+    "manganese", "iron", "cobalt", "nickel", "copper", "zinc", "gallium", "germanium",
+    "arsenic", "selenium", "bromine", "krypton", "rubidium", "strontium", "yttrium",
+    "zirconium", "niobium", "molybdenum", "technetium", "ruthenium", "rhodium", "palladium",
+    "silver", "cadmium", "indium", "tin", "antimony", "tellurium", "iodine", "xenon", "caesium",
+    "barium", "lanthanum", "cerium", "praseodymium", "neodymium", "promethium", "samarium",
+    "europium", "gadolinium", "terbium", "dysprosium", "holmium", "erbium", "thulium",
+    "ytterbium", "lutetium", "hafnium", "tantalum", "tungsten", "rhenium", "osmium", "iridium",
+    "platinum", "gold", "mercury", "thallium", "lead", "bismuth", "polonium", "astatine",
+    "radon", "francium", "radium", "actinium", "thorium", "protactinium", "uranium",
+    "neptunium", "plutonium", "americium", "curium", "berkelium", "californium", "einsteinium",
+    "fermium", "mendelevium", "nobelium", "lawrencium", "rutherfordium", "dubnium",
+    "seaborgium", "bohrium", "hassium", "meitnerium", "darmstadtium", "roentgenium",
+    "copernicium", "nihonium", "flerovium", "moscovium", "livermorium", "tennessine",
+    "oganesson",
+];
+
+/// American spellings next to `ELEMENT_NAMES`.
+const ELEMENT_ALIASES: [(&str, u8); 3] = [("aluminum", 13), ("sulphur", 16), ("cesium", 55)];
+
+const SHELL_LETTERS: &str = "spdfghik";
+
+impl BasisFormat {
+    /// Format named by the extension of `path` (`.json`, `.nw`, `.gbs`, `.mpro`, `.c4bas`,
+    /// `.tm`, `.orca`), or a CFOUR file called `GENBAS`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = Path::new(path);
+        if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.eq_ignore_ascii_case("genbas"))
+        {
+            return Some(BasisFormat::Genbas);
+        }
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Some(BasisFormat::BseJson),
+            "nw" | "nwchem" => Some(BasisFormat::NWChem),
+            "gbs" | "g94" => Some(BasisFormat::Gaussian94),
+            "mpro" | "molpro" => Some(BasisFormat::Molpro),
+            "c4bas" | "genbas" => Some(BasisFormat::Genbas),
+            "tm" | "turbomole" => Some(BasisFormat::Turbomole),
+            "orca" => Some(BasisFormat::Orca),
+            _ => None,
+        }
+    }
+
+    /// Guess the format from the keywords of `content`.
+    pub fn detect(content: &str) -> Option<Self> {
+        let lines: Vec<String> = content
+            .lines()
+            .map(|line| line.trim().to_ascii_lowercase())
+            .filter(|line| !line.is_empty() && !line.starts_with(['!', '#']))
+            .collect();
+        let any = |f: fn(&str) -> bool| lines.iter().any(|line| f(line));
+
+        if lines.first()?.starts_with('{') {
+            Some(BasisFormat::BseJson)
+        } else if any(|line| line.starts_with("$basis")) {
+            Some(BasisFormat::Turbomole)
+        } else if any(|line| line.starts_with("$data") || line.starts_with("newgto")) {
+            Some(BasisFormat::Orca)
+        } else if any(|line| line == "****") {
+            Some(BasisFormat::Gaussian94)
+        } else if any(|line| {
+            line.replace(' ', "").starts_with("basis=")
+                || line
+                    .split_once(',')
+                    .is_some_and(|(l, _)| l.len() == 1 && SHELL_LETTERS.contains(l))
+        }) {
+            Some(BasisFormat::Molpro)
+        } else if any(|line| line.split_whitespace().next() == Some("basis")) {
+            Some(BasisFormat::NWChem)
+        } else if genbas_header(&lines[0]).is_some() {
+            Some(BasisFormat::Genbas)
+        } else {
+            None
+        }
+    }
+
+    /// Shells of every element in `content`, the coefficients scaled by `norm`.
+    pub fn parse(self, content: &str, norm: Normalization) -> Result<BasisMap, CintError> {
+        let basis = match self {
+            BasisFormat::BseJson => return parse_json(content, norm),
+            BasisFormat::NWChem => parse_nwchem(content)?,
+            BasisFormat::Gaussian94 => parse_gaussian(content)?,
+            BasisFormat::Molpro => parse_molpro(content)?,
+            BasisFormat::Genbas => parse_genbas(content)?,
+            BasisFormat::Turbomole => parse_turbomole(content)?,
+            BasisFormat::Orca => parse_orca(content)?,
+        };
+        Ok(basis
+            .into_iter()
+            .map(|(charge, shells)| {
+                let shells = shells.into_iter().map(|cgto| cgto.with_norm(norm));
+                (charge, shells.collect())
+            })
+            .collect())
+    }
+
+    /// Text of `basis` in this format, BSE JSON, NWChem or Gaussian94. Coefficients scaled
+    /// by `Normalization::Primitive` are written without `CGTO::norm`. The scale of the
+    /// file coefficients is lost by `Normalization::Contracted`, which is rejected.
+    pub fn write(self, basis: &BasisMap, norm: Normalization) -> Result<String, CintError> {
+        let basis: BasisMap = basis
+            .iter()
+            .map(|(charge, shells)| {
+                let shells = shells.iter().cloned().map(|cgto| match norm {
+                    Normalization::None => Ok(cgto),
+                    Normalization::Primitive => Ok(cgto.denorm()),
+                    Normalization::Contracted => Err(export(
+                        "contracted normalization cannot be undone, write the primitive one",
+                    )),
+                });
+                Ok((*charge, shells.collect::<Result<_, _>>()?))
+            })
+            .collect::<Result<_, CintError>>()?;
+        match self {
+            BasisFormat::BseJson => write_json(&basis),
+            BasisFormat::NWChem => write_nwchem(&basis),
+            BasisFormat::Gaussian94 => write_gaussian(&basis),
+            format => Err(export(format!("no writer for {:?}", format))),
+        }
+    }
+}
+
+/// Read a basis set file, the format taken from its name or else from its content.
+pub fn read_basis(path: &str, norm: Normalization) -> Result<BasisMap, CintError> {
+    let basis_file_err = |msg: String| CintError::BasisFile {
+        path: path.to_string(),
+        msg,
+    };
+    let content = fs::read_to_string(path).map_err(|why| basis_file_err(why.to_string()))?;
+    let format = BasisFormat::from_path(path)
+        .or_else(|| BasisFormat::detect(&content))
+        .ok_or_else(|| basis_file_err("unknown basis set format".to_string()))?;
+    format
+        .parse(&content, norm)
+        .map_err(|why| basis_file_err(why.to_string()))
+}
+
+/// Write `basis` to `path` in the format named by its extension.
+pub fn write_basis(path: &str, basis: &BasisMap, norm: Normalization) -> Result<(), CintError> {
+    let basis_file_err = |msg: String| CintError::BasisFile {
+        path: path.to_string(),
+        msg,
+    };
+    let format = BasisFormat::from_path(path)
+        .ok_or_else(|| basis_file_err("unknown basis set format".to_string()))?;
+    fs::write(path, format.write(basis, norm)?).map_err(|why| basis_file_err(why.to_string()))
+}
+
+fn syntax(line: usize, msg: impl Into<String>) -> CintError {
+    CintError::BasisSyntax {
+        line,
+        msg: msg.into(),
+    }
+}
+
+/// Number of a basis file, Fortran `D` exponents included.
+fn number(token: &str, line: usize) -> Result<f64, CintError> {
+    token
+        .replace(['D', 'd'], "E")
+        .parse()
+        .map_err(|_| syntax(line, format!("bad number `{}`", token)))
+}
+
+fn count(token: &str, line: usize) -> Result<usize, CintError> {
+    token
+        .parse()
+        .map_err(|_| syntax(line, format!("bad count `{}`", token)))
+}
+
+/// Nuclear charge of an element symbol or name, in any case.
+fn element(token: &str, line: usize) -> Result<u8, CintError> {
+    let lower = token.to_ascii_lowercase();
+    PERIODIC_TABLE
+        .iter()
+        .position(|symbol| symbol.eq_ignore_ascii_case(token))
+        .or_else(|| ELEMENT_NAMES.iter().position(|name| *name == lower))
+        .map(|i| (i + 1) as u8)
+        .or_else(|| {
+            ELEMENT_ALIASES
+                .iter()
+                .find(|(name, _)| *name == lower)
+                .map(|(_, charge)| *charge)
+        })
+        .ok_or_else(|| syntax(line, format!("unknown element `{}`", token)))
+}
+
+/// Angular momenta of a shell label, `SP` and `L` for the shared-exponent s and p pair.
+fn angls(label: &str, line: usize) -> Result<Vec<u8>, CintError> {
+    let lower = label.to_ascii_lowercase();
+    match lower.as_str() {
+        "sp" | "l" => Ok(vec![0, 1]),
+        _ => match SHELL_LETTERS.find(&lower) {
+            Some(angl) if lower.len() == 1 => Ok(vec![angl as u8]),
+            _ => Err(syntax(line, format!("unknown shell `{}`", label))),
+        },
+    }
+}
+
+/// Shell of rows `exp coeff...`, one coefficient column per entry of `angl` or, for a
+/// single angular momentum, one per contraction.
+fn shell(angl: Vec<u8>, rows: Vec<Vec<f64>>, line: usize) -> Result<CGTO, CintError> {
+    let ncol = rows.first().map_or(0, |row| row.len());
+    if ncol < 2 || rows.iter().any(|row| row.len() != ncol) {
+        return Err(syntax(
+            line,
+            "expected an exponent and coefficients on every row",
+        ));
+    }
+    if angl.len() > 1 && ncol - 1 != angl.len() {
+        return Err(syntax(
+            line,
+            format!("expected {} coefficients", angl.len()),
+        ));
+    }
+    Ok(CGTO {
+        kappa_of: 0,
+        angl,
+        exp: rows.iter().map(|row| row[0]).collect(),
+        coeff: (1..ncol)
+            .map(|ictr| rows.iter().map(|row| row[ictr]).collect())
+            .collect(),
+    })
+}
+
+/// Non-empty lines with their 1-based numbers, comments after `comment` removed.
+fn lines(content: &str, comment: char) -> Vec<(usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split(comment).next().unwrap_or("").trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect()
+}
+
+/// `nprim` rows of numbers after `lines[*i]`, dropping the first `skip` columns.
+fn rows(
+    lines: &[(usize, &str)],
+    i: &mut usize,
+    nprim: usize,
+    skip: usize,
+    line: usize,
+) -> Result<Vec<Vec<f64>>, CintError> {
+    let rows = lines
+        .get(*i..*i + nprim)
+        .ok_or_else(|| syntax(line, format!("expected {} primitives", nprim)))?;
+    *i += nprim;
+    rows.iter()
+        .map(|(n, row)| {
+            row.split_whitespace()
+                .skip(skip)
+                .map(|token| number(token, *n))
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_json(content: &str, norm: Normalization) -> Result<BasisMap, CintError> {
+    let json: JsonBasis =
+        serde_json::from_str(content).map_err(|why| syntax(why.line(), why.to_string()))?;
+    (1..=PERIODIC_TABLE.len() as u8)
+        .filter_map(|charge| {
+            let ele = json.get_elements(charge)?;
+            Some(ele.to_cgto(0, norm).map(|shells| (charge, shells)))
+        })
+        .collect()
+}
+
+/// `BASIS ... END` blocks of `H S` headers followed by `exp coeff...` rows.
+fn parse_nwchem(content: &str) -> Result<BasisMap, CintError> {
+    // element, angular momenta, rows and header line of the shell being read
+    type Shell = (u8, Vec<u8>, Vec<Vec<f64>>, usize);
+    let mut basis = BasisMap::new();
+    let mut skip = false;
+    let mut current: Option<Shell> = None;
+    let mut flush = |current: &mut Option<Shell>| match current.take() {
+        Some((charge, angl, rows, line)) => {
+            let cgto = shell(angl, rows, line)?;
+            basis.entry(charge).or_default().push(cgto);
+            Ok(())
+        }
+        None => Ok(()),
+    };
+
+    for (n, line) in lines(content, '#') {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let keyword = tokens[0].to_ascii_lowercase();
+        match keyword.as_str() {
+            "basis" => skip = false,
+            "ecp" | "so" => skip = true,
+            "end" => {
+                flush(&mut current)?;
+                skip = false;
+            }
+            _ if skip => {}
+            _ if number(tokens[0], n).is_ok() => match current.as_mut() {
+                Some((_, _, rows, _)) => rows.push(
+                    tokens
+                        .iter()
+                        .map(|token| number(token, n))
+                        .collect::<Result<_, _>>()?,
+                ),
+                None => return Err(syntax(n, "primitive before any shell")),
+            },
+            _ => {
+                flush(&mut current)?;
+                let label = tokens
+                    .get(1)
+                    .ok_or_else(|| syntax(n, "expected `ELEMENT SHELL`"))?;
+                current = Some((element(tokens[0], n)?, angls(label, n)?, Vec::new(), n));
+            }
+        }
+    }
+    flush(&mut current)?;
+    Ok(basis)
+}
+
+/// `****`-separated element blocks of `S 3 1.00` shells, the exponents scaled by the
+/// square of the scale factor.
+fn parse_gaussian(content: &str) -> Result<BasisMap, CintError> {
+    let mut basis = BasisMap::new();
+    let lines = lines(content, '!');
+    let mut charge = None;
+    let mut i = 0;
+    while let Some(&(n, line)) = lines.get(i) {
+        i += 1;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if line == "****" {
+            charge = None;
+            continue;
+        }
+        if tokens[0].to_ascii_uppercase().ends_with("-ECP") {
+            break;
+        }
+        let Some(charge) = charge else {
+            charge = Some(element(tokens[0].trim_start_matches('-'), n)?);
+            continue;
+        };
+        if tokens.len() < 2 {
+            return Err(syntax(n, "expected `SHELL NPRIM SCALE`"));
+        }
+        let scale = match tokens.get(2) {
+            Some(token) => number(token, n)?,
+            None => 1.0,
+        };
+        let rows = rows(&lines, &mut i, count(tokens[1], n)?, 0, n)?;
+        let mut cgto = shell(angls(tokens[0], n)?, rows, n)?;
+        cgto.exp.iter_mut().for_each(|e| *e *= scale * scale);
+        basis.entry(charge).or_default().push(cgto);
+    }
+    Ok(basis)
+}
+
+/// `s, H, exps...` shells followed by `c, 1.3, coeffs...` contractions over primitives
+/// 1 to 3; a shell without contractions is uncontracted.
+fn parse_molpro(content: &str) -> Result<BasisMap, CintError> {
+    let mut basis = BasisMap::new();
+    let mut current: Option<(u8, CGTO)> = None;
+    let mut flush = |current: &mut Option<(u8, CGTO)>| {
+        if let Some((charge, cgto)) = current.take() {
+            let shells = basis.entry(charge).or_default();
+            match cgto.coeff.is_empty() {
+                true => shells.extend(cgto.exp.iter().map(|e| CGTO {
+                    exp: vec![*e],
+                    coeff: vec![vec![1.0]],
+                    ..cgto.clone()
+                })),
+                false => shells.push(cgto),
+            }
+        }
+    };
+
+    // inside an `ECP, rb, 28, 4;` section, which runs to the end of its block
+    let mut ecp = false;
+    for (n, line) in lines(content, '!') {
+        let block_end = line.ends_with('}');
+        if ecp {
+            ecp = !block_end;
+            continue;
+        }
+        let line = line.trim_end_matches('}').trim();
+        if line.is_empty()
+            || line
+                .replace(' ', "")
+                .to_ascii_lowercase()
+                .starts_with("basis=")
+        {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields[0].eq_ignore_ascii_case("ecp") {
+            ecp = !block_end;
+            continue;
+        }
+        if fields.len() < 3 {
+            return Err(syntax(n, "expected a shell or a contraction"));
+        }
+        if fields[0].eq_ignore_ascii_case("c") {
+            let (_, cgto) = current
+                .as_mut()
+                .ok_or_else(|| syntax(n, "contraction before any shell"))?;
+            let (first, last) = fields[1]
+                .split_once('.')
+                .ok_or_else(|| syntax(n, format!("bad range `{}`", fields[1])))?;
+            let (first, last) = (count(first, n)?, count(last, n)?);
+            let coeff = fields[2..]
+                .iter()
+                .map(|token| number(token, n))
+                .collect::<Result<Vec<f64>, _>>()?;
+            if first == 0 || last > cgto.exp.len() || last + 1 != first + coeff.len() {
+                return Err(syntax(n, format!("range `{}` does not match", fields[1])));
+            }
+            let mut row = vec![0.0; cgto.exp.len()];
+            row[first - 1..last].copy_from_slice(&coeff);
+            cgto.coeff.push(row);
+        } else {
+            flush(&mut current);
+            let cgto = CGTO {
+                kappa_of: 0,
+                angl: angls(fields[0], n)?,
+                exp: fields[2..]
+                    .iter()
+                    .map(|token| number(token, n))
+                    .collect::<Result<_, _>>()?,
+                coeff: Vec::new(),
+            };
+            current = Some((element(fields[1], n)?, cgto));
+        }
+    }
+    flush(&mut current);
+    Ok(basis)
+}
+
+/// Element symbol of a GENBAS entry header `H:STO-3G`.
+fn genbas_header(line: &str) -> Option<&str> {
+    let (symbol, name) = line.split_once(':')?;
+    let symbol_like =
+        (1..=3).contains(&symbol.len()) && symbol.chars().all(|c| c.is_ascii_alphabetic());
+    match symbol_like && !name.is_empty() && !line.contains(char::is_whitespace) {
+        true => Some(symbol),
+        false => None,
+    }
+}
+
+/// Entries of a header, a comment line, the number of angular momenta, their values,
+/// contraction and primitive counts, then per angular momentum the exponents and the
+/// coefficient matrix with one row per primitive.
+fn parse_genbas(content: &str) -> Result<BasisMap, CintError> {
+    let mut basis = BasisMap::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let (n, line) = (i + 1, lines[i].trim());
+        i += 1;
+        if line.is_empty() || line.starts_with(['!', '#']) {
+            continue;
+        }
+        let symbol = genbas_header(line).ok_or_else(|| syntax(n, "expected `ELEMENT:NAME`"))?;
+        let charge = element(symbol, n)?;
+        i += 1;
+
+        let mut tokens = Vec::new();
+        while i < lines.len() && genbas_header(lines[i].trim()).is_none() {
+            tokens.extend(lines[i].split_whitespace().map(|token| (i + 1, token)));
+            i += 1;
+        }
+        let mut tokens = tokens.into_iter();
+        let mut next = || tokens.next().ok_or_else(|| syntax(n, "entry ends early"));
+        let mut counts = |len: usize| -> Result<Vec<usize>, CintError> {
+            (0..len)
+                .map(|_| next().and_then(|(n, token)| count(token, n)))
+                .collect()
+        };
+        let nshell = counts(1)?[0];
+        let (angl, nctr, nprim) = (counts(nshell)?, counts(nshell)?, counts(nshell)?);
+
+        let shells = basis.entry(charge).or_default();
+        for ishl in 0..nshell {
+            let mut numbers = |len: usize| -> Result<Vec<f64>, CintError> {
+                (0..len)
+                    .map(|_| next().and_then(|(n, token)| number(token, n)))
+                    .collect()
+            };
+            let exp = numbers(nprim[ishl])?;
+            let coeff = numbers(nprim[ishl] * nctr[ishl])?;
+            shells.push(CGTO {
+                kappa_of: 0,
+                angl: vec![angl[ishl] as u8],
+                exp,
+                coeff: (0..nctr[ishl])
+                    .map(|ictr| {
+                        coeff
+                            .iter()
+                            .skip(ictr)
+                            .step_by(nctr[ishl])
+                            .copied()
+                            .collect()
+                    })
+                    .collect(),
+            });
+        }
+    }
+    Ok(basis)
+}
+
+/// `$basis` section of `*`-delimited element headers `h sto-3g` and `3 s` shells.
+fn parse_turbomole(content: &str) -> Result<BasisMap, CintError> {
+    let mut basis = BasisMap::new();
+    let lines = lines(content, '#');
+    let (mut in_basis, mut charge) = (true, None);
+    let mut i = 0;
+    while let Some(&(n, line)) = lines.get(i) {
+        i += 1;
+        if line.starts_with('$') {
+            in_basis = line.starts_with("$basis");
+            continue;
+        }
+        if !in_basis || line == "*" {
+            continue;
+        }
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match (tokens.len(), tokens[0].parse::<usize>()) {
+            (2, Ok(nprim)) => {
+                let charge = charge.ok_or_else(|| syntax(n, "shell before any element"))?;
+                let rows = rows(&lines, &mut i, nprim, 0, n)?;
+                let cgto = shell(angls(tokens[1], n)?, rows, n)?;
+                basis.entry(charge).or_default().push(cgto);
+            }
+            _ => charge = Some(element(tokens[0], n)?),
+        }
+    }
+    Ok(basis)
+}
+
+/// `$DATA` library of element names followed by `S 3` shells of numbered primitives, or
+/// `NewGTO H ... end` blocks of an input file.
+fn parse_orca(content: &str) -> Result<BasisMap, CintError> {
+    let mut basis = BasisMap::new();
+    let lines = lines(content, '#');
+    let mut charge = None;
+    let mut i = 0;
+    while let Some(&(n, line)) = lines.get(i) {
+        i += 1;
+        if line.starts_with(['$', '%']) || line.eq_ignore_ascii_case("end") {
+            continue;
+        }
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens[0].eq_ignore_ascii_case("newecp") {
+            while lines
+                .get(i)
+                .is_some_and(|(_, line)| !line.eq_ignore_ascii_case("end"))
+            {
+                i += 1;
+            }
+            continue;
+        }
+        match tokens.as_slice() {
+            [keyword, symbol] if keyword.eq_ignore_ascii_case("newgto") => {
+                charge = Some(element(symbol.trim_matches('"'), n)?)
+            }
+            [name] => charge = Some(element(name, n)?),
+            [label, nprim] => {
+                let charge = charge.ok_or_else(|| syntax(n, "shell before any element"))?;
+                let rows = rows(&lines, &mut i, count(nprim, n)?, 1, n)?;
+                let cgto = shell(angls(label, n)?, rows, n)?;
+                basis.entry(charge).or_default().push(cgto);
+            }
+            _ => return Err(syntax(n, "expected an element or a shell")),
+        }
+    }
+    Ok(basis)
+}
+
+fn export(msg: impl Into<String>) -> CintError {
+    CintError::BasisExport { msg: msg.into() }
+}
+
+fn symbol(charge: u8) -> Result<&'static str, CintError> {
+    (charge as usize)
+        .checked_sub(1)
+        .and_then(|i| PERIODIC_TABLE.get(i))
+        .copied()
+        .ok_or_else(|| export(format!("no element of nuclear charge {}", charge)))
+}
+
+/// Shell label of `angl`, the inverse of `angls`.
+fn label(angl: &[u8]) -> Result<String, CintError> {
+    match angl {
+        [0, 1] => Ok("SP".to_string()),
+        [angl] => SHELL_LETTERS
+            .chars()
+            .nth(*angl as usize)
+            .map(|c| c.to_ascii_uppercase().to_string())
+            .ok_or_else(|| export(format!("no shell label for angular momentum {}", angl))),
+        _ => Err(export(format!("no shell label for {:?}", angl))),
+    }
+}
+
+/// Shells with a label of their own: SP pairs are kept, other shared-exponent shells are
+/// split by angular momentum and, without `general`, so are general contractions. A split
+/// contraction keeps only its primitives of nonzero coefficient.
+fn split(cgto: &CGTO, general: bool) -> Vec<CGTO> {
+    if cgto.angl == [0, 1] || (cgto.is_general() && (general || cgto.coeff.len() == 1)) {
+        return vec![cgto.clone()];
+    }
+    (0..cgto.coeff.len())
+        .map(|ictr| {
+            let (exp, coeff): (Vec<f64>, Vec<f64>) = cgto
+                .exp
+                .iter()
+                .zip(&cgto.coeff[ictr])
+                .filter(|(_, c)| **c != 0.0)
+                .unzip();
+            CGTO {
+                kappa_of: cgto.kappa_of,
+                angl: vec![cgto.angl_of_coeff(ictr)],
+                exp,
+                coeff: vec![coeff],
+            }
+        })
+        .collect()
+}
+
+/// Shortest text that reads back as the same `f64`.
+fn value(x: f64) -> String {
+    format!("{:E}", x)
+}
+
+/// Rows `exp coeff...` of `cgto`, one per primitive.
+fn table(cgto: &CGTO) -> String {
+    (0..cgto.exp.len())
+        .map(|ip| {
+            let coeff = cgto
+                .coeff
+                .iter()
+                .map(|row| format!(" {:>22}", value(row[ip])));
+            format!("{:>22}{}\n", value(cgto.exp[ip]), coeff.collect::<String>())
+        })
+        .collect()
+}
+
+fn write_json(basis: &BasisMap) -> Result<String, CintError> {
+    let values = |xs: &[f64]| xs.iter().map(|x| value(*x)).collect::<Vec<_>>();
+    let elements = basis
+        .iter()
+        .map(|(charge, shells)| {
+            symbol(*charge)?;
+            let shells: Vec<_> = shells
+                .iter()
+                .map(|cgto| {
+                    json!({
+                        "function_type": match cgto.angl.iter().all(|angl| *angl < 2) {
+                            true => "gto",
+                            false => "gto_spherical",
+                        },
+                        "region": "",
+                        "angular_momentum": cgto.angl,
+                        "exponents": values(&cgto.exp),
+                        "coefficients": cgto.coeff.iter().map(|row| values(row)).collect::<Vec<_>>(),
+                    })
+                })
+                .collect();
+            let element = json!({ "references": [], "electron_shells": shells });
+            Ok((charge.to_string(), element))
+        })
+        .collect::<Result<serde_json::Map<_, _>, CintError>>()?;
+    let json = json!({
+        "molssi_bse_schema": { "schema_type": "component", "schema_version": "0.1" },
+        "description": "",
+        "data_source": "",
+        "elements": elements,
+    });
+    serde_json::to_string_pretty(&json).map_err(|why| export(why.to_string()))
+}
+
+fn write_nwchem(basis: &BasisMap) -> Result<String, CintError> {
+    let mut text = String::from("BASIS \"ao basis\" PRINT\n");
+    for (charge, shells) in basis {
+        let symbol = symbol(*charge)?;
+        for cgto in shells.iter().flat_map(|cgto| split(cgto, true)) {
+            text += &format!("{:<4} {}\n", symbol, label(&cgto.angl)?);
+            text += &table(&cgto);
+        }
+    }
+    text += "END\n";
+    Ok(text)
+}
+
+fn write_gaussian(basis: &BasisMap) -> Result<String, CintError> {
+    let mut text = String::new();
+    for (charge, shells) in basis {
+        text += &format!("{:<4} 0\n", symbol(*charge)?);
+        for cgto in shells.iter().flat_map(|cgto| split(cgto, false)) {
+            let nprim = cgto.exp.len();
+            text += &format!("{:<4} {:>3}   1.00\n", label(&cgto.angl)?, nprim);
+            text += &table(&cgto);
+        }
+        text += "****\n";
+    }
+    Ok(text)
+}
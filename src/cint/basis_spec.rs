@@ -0,0 +1,115 @@
+//! Assignment of basis sets to the atoms of an xyz input by atom index, atom label or
+//! element, with a default for the remaining atoms.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "basis-library")]
+use super::library;
+use super::{
+    basis_format::{read_basis, BasisMap},
+    error::CintError,
+    rawdata::Normalization,
+    AtomIndex,
+};
+
+/// Where the shells of a basis set come from: a file in any `BasisFormat`, shells in
+/// memory, which are used as they are, or a basis set of `cint::library` by name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BasisSource {
+    File(String),
+    Basis(BasisMap),
+    #[cfg(feature = "basis-library")]
+    Named(String),
+}
+
+impl From<&str> for BasisSource {
+    fn from(path: &str) -> Self {
+        BasisSource::File(path.to_string())
+    }
+}
+
+impl From<String> for BasisSource {
+    fn from(path: String) -> Self {
+        BasisSource::File(path)
+    }
+}
+
+impl From<BasisMap> for BasisSource {
+    fn from(basis: BasisMap) -> Self {
+        BasisSource::Basis(basis)
+    }
+}
+
+impl BasisSource {
+    pub fn load(&self, norm: Normalization) -> Result<BasisMap, CintError> {
+        match self {
+            BasisSource::File(path) => read_basis(path, norm),
+            BasisSource::Basis(basis) => Ok(basis.clone()),
+            #[cfg(feature = "basis-library")]
+            BasisSource::Named(name) => library::basis(name, norm),
+        }
+    }
+}
+
+/// Basis set of every atom: the first of its atom index (position in the xyz input), its
+/// label (`Fe1`, `O_diffuse`, without ghost markers), its element and the default.
+#[derive(Debug, Clone, Default)]
+pub struct BasisSpec {
+    sources: Vec<BasisSource>,
+    default: Option<usize>,
+    elements: BTreeMap<u8, usize>,
+    labels: BTreeMap<String, usize>,
+    atoms: BTreeMap<AtomIndex, usize>,
+}
+
+impl BasisSpec {
+    /// `default` for every atom, until overridden.
+    pub fn new(default: impl Into<BasisSource>) -> Self {
+        let mut spec = Self::default();
+        spec.default = Some(spec.push(default.into()));
+        spec
+    }
+
+    pub fn element(mut self, charge: u8, source: impl Into<BasisSource>) -> Self {
+        let isrc = self.push(source.into());
+        self.elements.insert(charge, isrc);
+        self
+    }
+
+    pub fn label(mut self, label: &str, source: impl Into<BasisSource>) -> Self {
+        let isrc = self.push(source.into());
+        self.labels.insert(label.to_string(), isrc);
+        self
+    }
+
+    pub fn atom(mut self, iatm: AtomIndex, source: impl Into<BasisSource>) -> Self {
+        let isrc = self.push(source.into());
+        self.atoms.insert(iatm, isrc);
+        self
+    }
+
+    /// Index of `source`, shared by equal sources so that their atoms stay in one group.
+    fn push(&mut self, source: BasisSource) -> usize {
+        match self.sources.iter().position(|s| *s == source) {
+            Some(isrc) => isrc,
+            None => {
+                self.sources.push(source);
+                self.sources.len() - 1
+            }
+        }
+    }
+
+    /// Index of the source of atom `iatm` labelled `label`, `None` when nothing covers it.
+    pub(super) fn source_of(&self, iatm: AtomIndex, label: &str, charge: u8) -> Option<usize> {
+        self.atoms
+            .get(&iatm)
+            .or_else(|| self.labels.get(label))
+            .or_else(|| self.elements.get(&charge))
+            .or(self.default.as_ref())
+            .copied()
+    }
+
+    pub(super) fn load(&self, isrc: usize, norm: Normalization) -> Result<BasisMap, CintError> {
+        self.sources[isrc].load(norm)
+    }
+}
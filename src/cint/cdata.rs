@@ -1,19 +1,24 @@
-use core::io::bse::JsonBasis;
-use std::{collections::BTreeMap, fs};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, ops::Deref};
 
 
 use super::{
+    auxbasis::aug_etb,
+    basis_format::BasisMap,
+    basis_spec::{BasisSource, BasisSpec},
+    error::CintError,
     intor::Intor,
     libcint::{
-        ANG_MAX, NGRIDS, PTR_COMMON_ORIG, PTR_ENV_START, PTR_EXPCUTOFF, PTR_F12_ZETA, PTR_GRIDS,
-        PTR_GTG_ZETA, PTR_RANGE_OMEGA, PTR_RINV_ORIG, PTR_RINV_ZETA,
+        ANG_MAX, ATM_SLOTS, BAS_SLOTS, NGRIDS, PTR_COMMON_ORIG, PTR_ENV_START, PTR_EXPCUTOFF,
+        PTR_F12_ZETA, PTR_GRIDS, PTR_GTG_ZETA, PTR_RANGE_OMEGA, PTR_RINV_ORIG, PTR_RINV_ZETA,
     },
-    rawdata::{AtomGroup, CintAtomGroup, GetCGTO},
-    AtomIndex, BasisIndex, NAtom,
+    npy,
+    rawdata::{AtomGroup, CintAtomGroup, Normalization, CGTO},
+    AtomIndex, BasisIndex, NAtom, NUC_FRAC_CHARGE, NUC_GAUSS, NUC_POINT,
 };
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct CintAtom {
     charge_of: i32,
     coord: i32,
@@ -37,7 +42,7 @@ impl CintAtom {
 }
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct CintBasis {
     pub(super) atom_of: i32,
     pub(super) ang_of: i32,
@@ -64,42 +69,183 @@ impl CintBasis {
     }
 }
 
+/// External charge without basis functions, e.g. an MM site, at `coord` (bohr). With
+/// `zeta > 0` it is smeared into the normalized Gaussian `(zeta/pi)^(3/2) exp(-zeta r^2)`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PointCharge {
+    pub coord: [f64; 3],
+    pub charge: f64,
+    pub zeta: f64,
+}
+
+impl PointCharge {
+    pub fn new(coord: [f64; 3], charge: f64) -> Self {
+        Self {
+            coord,
+            charge,
+            zeta: 0.0,
+        }
+    }
+
+    pub fn gaussian(coord: [f64; 3], charge: f64, zeta: f64) -> Self {
+        Self {
+            coord,
+            charge,
+            zeta,
+        }
+    }
+}
+
+/// Exponent standing in for a point charge, as in PySCF's `fakemol_for_charges`.
+const POINT_CHARGE_ZETA: f64 = 1e16;
+
+/// `atm` and `env` with one basis-less atom per charge appended, and an s shell on each
+/// holding the normalized charge distribution, the third centre of `int3c2e` for
+/// `Intor::intor_point_charges`.
+pub(super) fn charge_shells(
+    atm: &[CintAtom],
+    env: &CintEnv,
+    charges: &[PointCharge],
+) -> (Vec<CintAtom>, Vec<CintBasis>, CintEnv) {
+    let mut atm = atm.to_vec();
+    let mut env = env.clone();
+    let mut bas = Vec::with_capacity(charges.len());
+    for charge in charges {
+        let ptr = env.data.len() as i32;
+        let zeta = match charge.zeta > 0.0 {
+            true => charge.zeta,
+            false => POINT_CHARGE_ZETA,
+        };
+        // libcint s functions carry 1/(2 sqrt(pi)), the shell then integrates to one
+        let coeff = 2.0 * zeta.powf(1.5) / std::f64::consts::PI;
+        env.data.extend(charge.coord);
+        env.data.extend([zeta, coeff]);
+        bas.push(CintBasis {
+            atom_of: atm.len() as i32,
+            ang_of: 0,
+            nprim_of: 1,
+            nctr_of: 1,
+            kappa_of: 0,
+            ptr_exp: ptr + 3,
+            ptr_coeff: ptr + 4,
+            reserve_baslot: 0,
+        });
+        atm.push(CintAtom {
+            coord: ptr,
+            nuc_mod_of: NUC_POINT,
+            ..CintAtom::empty()
+        });
+    }
+    (atm, bas, env)
+}
+
 const ATM_OFFSET: usize = PTR_ENV_START as usize;
-const ATM_SLOT: usize = 4;
+/// Env doubles per atom: coordinates (3), `zeta` and `frac_charge`.
+const ATM_SLOT: usize = 5;
 
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CintEnv {
     pub(self) data: Vec<f64>,
 }
 
+/// Where an operator origin (`rinv`, common/gauge origin) is placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Origin {
+    Atom(AtomIndex),
+    Point([f64; 3]),
+}
+
+impl From<AtomIndex> for Origin {
+    fn from(iatm: AtomIndex) -> Self {
+        Origin::Atom(iatm)
+    }
+}
+
+impl From<[f64; 3]> for Origin {
+    fn from(point: [f64; 3]) -> Self {
+        Origin::Point(point)
+    }
+}
+
 impl CintEnv {
-    pub fn expcutoff(&mut self) -> &mut f64 {
-        &mut self.data[PTR_EXPCUTOFF as usize]
+    fn vec3(&self, ptr: u32) -> [f64; 3] {
+        let ptr = ptr as usize;
+        [self.data[ptr], self.data[ptr + 1], self.data[ptr + 2]]
+    }
+    fn set_vec3(&mut self, ptr: u32, value: [f64; 3]) {
+        let ptr = ptr as usize;
+        self.data[ptr..ptr + 3].copy_from_slice(&value);
+    }
+
+    pub fn expcutoff(&self) -> f64 {
+        self.data[PTR_EXPCUTOFF as usize]
+    }
+    pub fn set_expcutoff(&mut self, expcutoff: f64) {
+        self.data[PTR_EXPCUTOFF as usize] = expcutoff;
+    }
+    pub fn common_orig(&self) -> [f64; 3] {
+        self.vec3(PTR_COMMON_ORIG)
+    }
+    pub fn set_common_orig(&mut self, orig: [f64; 3]) {
+        self.set_vec3(PTR_COMMON_ORIG, orig);
+    }
+    pub fn rinv_orig(&self) -> [f64; 3] {
+        self.vec3(PTR_RINV_ORIG)
+    }
+    pub fn set_rinv_orig(&mut self, orig: [f64; 3]) {
+        self.set_vec3(PTR_RINV_ORIG, orig);
+    }
+    pub fn rinv_zeta(&self) -> f64 {
+        self.data[PTR_RINV_ZETA as usize]
+    }
+    pub fn set_rinv_zeta(&mut self, zeta: f64) {
+        self.data[PTR_RINV_ZETA as usize] = zeta;
     }
-    pub fn common_orig(&mut self) -> &mut [f64] {
-        &mut self.data[(PTR_COMMON_ORIG as usize)..(PTR_RINV_ORIG as usize)]
+    pub fn range_omega(&self) -> f64 {
+        self.data[PTR_RANGE_OMEGA as usize]
     }
-    pub fn rinv_orig(&mut self) -> &mut [f64] {
-        &mut self.data[(PTR_RINV_ORIG as usize)..(PTR_RINV_ZETA as usize)]
+    pub fn set_range_omega(&mut self, omega: f64) {
+        self.data[PTR_RANGE_OMEGA as usize] = omega;
     }
-    pub fn rinv_zeta(&mut self) -> &mut f64 {
-        &mut self.data[PTR_RANGE_OMEGA as usize]
+    pub fn f12_zeta(&self) -> f64 {
+        self.data[PTR_F12_ZETA as usize]
     }
-    pub fn range_omega(&mut self) -> &mut f64 {
-        &mut self.data[PTR_RANGE_OMEGA as usize]
+    pub fn set_f12_zeta(&mut self, zeta: f64) {
+        self.data[PTR_F12_ZETA as usize] = zeta;
     }
-    pub fn f12_zeta(&mut self) -> &mut f64 {
-        &mut self.data[PTR_F12_ZETA as usize]
+    pub fn gtg_zeta(&self) -> f64 {
+        self.data[PTR_GTG_ZETA as usize]
     }
-    pub fn gtg_zeta(&mut self) -> &mut f64 {
-        &mut self.data[PTR_GTG_ZETA as usize]
+    pub fn set_gtg_zeta(&mut self, zeta: f64) {
+        self.data[PTR_GTG_ZETA as usize] = zeta;
     }
-    pub fn ngrids(&mut self) -> &mut f64 {
-        &mut self.data[NGRIDS as usize]
+    /// Number of grid points of the `*_grids` integrals.
+    pub fn ngrids(&self) -> usize {
+        self.data[NGRIDS as usize] as usize
     }
-    pub fn grids(&mut self) -> &mut [f64] {
-        &mut self.data[(PTR_GRIDS as usize)..(PTR_ENV_START as usize)]
+    /// Offset in env of the grid coordinates, `3 * ngrids` doubles.
+    pub fn ptr_grids(&self) -> usize {
+        self.data[PTR_GRIDS as usize] as usize
+    }
+    /// Grid points (bohr) of the `*_grids` integrals, read back from env.
+    pub fn grids(&self) -> Vec<[f64; 3]> {
+        let ptr = self.ptr_grids();
+        self.data[ptr..ptr + 3 * self.ngrids()]
+            .chunks(3)
+            .map(|x| [x[0], x[1], x[2]])
+            .collect()
+    }
+    /// Append `grids` (bohr) to env and point `PTR_GRIDS`/`NGRIDS` at them. Grids set
+    /// before are dropped when they are still the last block of env.
+    pub fn set_grids(&mut self, grids: &[[f64; 3]]) {
+        let (ptr, ngrids) = (self.ptr_grids(), self.ngrids());
+        if ngrids > 0 && ptr + 3 * ngrids == self.data.len() {
+            self.data.truncate(ptr);
+        }
+        self.data[PTR_GRIDS as usize] = self.data.len() as f64;
+        self.data[NGRIDS as usize] = grids.len() as f64;
+        self.data.extend(grids.iter().flatten());
     }
     pub fn atom(&mut self, iatm: usize) -> &mut [f64] {
         let start = ATM_OFFSET + ATM_SLOT * iatm;
@@ -109,23 +255,117 @@ impl CintEnv {
     pub fn as_ptr(&self) -> *const f64 {
         self.data.as_ptr()
     }
+    /// Coordinates of `origin`, an atom of `atm` or a point.
+    pub(super) fn origin_coord(
+        &self,
+        atm: &[CintAtom],
+        origin: Origin,
+    ) -> Result<[f64; 3], CintError> {
+        match origin {
+            Origin::Atom(iatm) => match atm.get(iatm) {
+                Some(atom) => Ok(self.vec3(atom.coord as u32)),
+                None => Err(CintError::AtomOutOfRange {
+                    atom: iatm,
+                    natm: atm.len(),
+                }),
+            },
+            Origin::Point(point) => Ok(point),
+        }
+    }
+
+    /// Global parameters, everything before `PTR_ENV_START`.
+    pub(super) fn globals(&self) -> &[f64] {
+        &self.data[..PTR_ENV_START as usize]
+    }
+
+    pub(super) fn restore_globals(&mut self, globals: &[f64]) {
+        self.data[..PTR_ENV_START as usize].copy_from_slice(globals);
+    }
+}
+
+/// Borrow of an env owner whose globals were changed by `GlobalsGuard::new`; they are
+/// restored on drop, also when unwinding.
+pub(super) struct GlobalsGuard<'g, T> {
+    owner: &'g mut T,
+    env: fn(&mut T) -> &mut CintEnv,
+    globals: Vec<f64>,
+}
+
+impl<'g, T> GlobalsGuard<'g, T> {
+    pub(super) fn new(
+        owner: &'g mut T,
+        env: fn(&mut T) -> &mut CintEnv,
+        set: impl FnOnce(&mut CintEnv),
+    ) -> Self {
+        let cint_env = env(owner);
+        let globals = cint_env.globals().to_vec();
+        set(cint_env);
+        GlobalsGuard {
+            owner,
+            env,
+            globals,
+        }
+    }
+}
+
+impl<T> Deref for GlobalsGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.owner
+    }
+}
+
+impl<T> Drop for GlobalsGuard<'_, T> {
+    fn drop(&mut self) {
+        (self.env)(self.owner).restore_globals(&self.globals);
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Version of the JSON checkpoint format of `CintDate::to_json`.
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Checkpoint<T> {
+    version: u32,
+    cint_date: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CintDate {
     atom_groups: Vec<CintAtomGroup>,
     basis_template: Vec<Vec<CintBasis>>,
     atmbas_index: Vec<AtomIndex>,
     atm: Vec<CintAtom>,
+    point_charges: Vec<PointCharge>,
     pub env: CintEnv,
 }
 
 impl CintDate {
-    pub fn new(atom_groups: Vec<impl AtomGroup>) -> Self {
-        atom_groups.iter().for_each(|atoms| match atoms.basis() {
-            Some(_) => {}
-            None => panic!(),
-        });
+    pub fn new(atom_groups: Vec<impl AtomGroup>) -> Result<Self, CintError> {
+        let basis_all: Vec<&Vec<CGTO>> = atom_groups
+            .iter()
+            .map(|atoms| {
+                atoms.basis().as_ref().ok_or(CintError::MissingBasis {
+                    charge: *atoms.charge_of(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        for (atoms, basis) in atom_groups.iter().zip(&basis_all) {
+            if let Some(ishl) = basis.iter().position(|cgto| !cgto.is_consistent()) {
+                return Err(CintError::Contraction {
+                    charge: *atoms.charge_of(),
+                    ishl,
+                });
+            }
+            let angl_all = basis.iter().flat_map(|cgto| cgto.angl.iter());
+            if let Some(angl) = angl_all.copied().find(|angl| *angl >= ANG_MAX as u8) {
+                return Err(CintError::AngularMomentum {
+                    charge: *atoms.charge_of(),
+                    angl,
+                });
+            }
+        }
 
         let natm: NAtom = atom_groups
             .iter()
@@ -133,31 +373,27 @@ impl CintDate {
             .sum();
 
         //
+        // every CGTO is stored as its exponents followed by its coefficient rows
         let mut ptr_exp = (PTR_ENV_START as usize) + natm * ATM_SLOT;
-        let mut ptr_coeff = ptr_exp;
-        let basis_template: Vec<Vec<CintBasis>> = atom_groups
+        let basis_template: Vec<Vec<CintBasis>> = basis_all
             .iter()
-            .map(|atoms| match &atoms.basis() {
-                Some(basis) => basis
+            .map(|basis| {
+                basis
                     .iter()
                     .flat_map(|cgto| {
-                        ptr_coeff += cgto.exp.len();
-                        let atom_basis: Vec<CintBasis> = cgto
-                            .angl
-                            .iter()
-                            .enumerate()
-                            .map(|(i, angl)| {
-                                assert!(*angl < ANG_MAX as u8);
+                        let nprim = cgto.exp.len();
+                        let mut ptr_coeff = ptr_exp + nprim;
+                        let atom_basis: Vec<CintBasis> = (0..cgto.angl.len())
+                            .map(|i| {
                                 let cint_basis = cgto.gen_bas(i, ptr_exp as i32, ptr_coeff as i32);
-                                ptr_coeff += cgto.exp.len();
+                                ptr_coeff += nprim * cgto.nctr();
                                 cint_basis
                             })
                             .collect();
-                        ptr_exp += cgto.exp.len() * (cgto.coeff.len() + 1);
+                        ptr_exp += nprim * (cgto.coeff.len() + 1);
                         atom_basis
                     })
-                    .collect(),
-                None => panic!(""),
+                    .collect()
             })
             .collect();
 
@@ -179,13 +415,17 @@ impl CintDate {
                     .map(|coor| {
                         let coord_i = (PTR_ENV_START as usize) + iatm * ATM_SLOT;
                         let zeta_i = coord_i + 3;
+                        let frac_charge_i = coord_i + 4;
                         iatm += 1;
                         CintAtom {
-                            charge_of: (*atoms.charge_of()).into(),
+                            charge_of: match atoms.ghost() {
+                                true => 0,
+                                false => (*atoms.charge_of()).into(),
+                            },
                             coord: coord_i as i32,
                             nuc_mod_of: (*atoms.nuc_mod_of()).into(),
                             zeta: zeta_i as i32,
-                            frac_charge: (*atoms.frac_charge()) as i32,
+                            frac_charge: frac_charge_i as i32,
                             reserve_atmslot: 0,
                         }
                     })
@@ -200,20 +440,30 @@ impl CintDate {
                 atoms
                     .coordinates()
                     .iter()
-                    .flat_map(|coor| vec![coor[0], coor[1], coor[2], *atoms.zeta()])
+                    .flat_map(|coor| {
+                        vec![
+                            coor[0],
+                            coor[1],
+                            coor[2],
+                            *atoms.zeta(),
+                            match atoms.ghost() {
+                                true => 0.0,
+                                false => *atoms.frac_charge(),
+                            },
+                        ]
+                    })
             })
             .collect();
 
-        let env_bas = atom_groups
+        let env_bas = basis_all
             .iter()
-            .flat_map(|atoms| match &atoms.basis() {
-                Some(basis) => basis.iter().flat_map(|cgto| {
+            .flat_map(|basis| {
+                basis.iter().flat_map(|cgto| {
                     cgto.exp
                         .iter()
                         .map(|x| *x)
                         .chain(cgto.coeff.iter().flat_map(|coeff| coeff.iter().map(|x| *x)))
-                }),
-                None => panic!(""),
+                })
             })
             .collect();
 
@@ -222,7 +472,7 @@ impl CintDate {
         };
 
         //
-        Self {
+        Ok(Self {
             atom_groups: atom_groups
                 .iter()
                 .map(|atom| CintAtomGroup::from_other_group(atom))
@@ -230,60 +480,472 @@ impl CintDate {
             basis_template,
             atmbas_index,
             atm,
+            point_charges: Vec::new(),
             env,
-        }
+        })
+    }
+
+    /// Atoms of `xyz_str` with the basis set file `basis_path`, in any `BasisFormat`.
+    pub fn fron_xyz(xyz_str: &str, basis_path: &str) -> Result<Self, CintError> {
+        Self::fron_xyz_with(xyz_str, basis_path, Normalization::Primitive)
+    }
+
+    /// Like `fron_xyz`, with the coefficients of the basis set file scaled by `norm`.
+    pub fn fron_xyz_with(
+        xyz_str: &str,
+        basis_path: &str,
+        norm: Normalization,
+    ) -> Result<Self, CintError> {
+        Self::fron_xyz_spec(xyz_str, &BasisSpec::new(basis_path), norm)
+    }
+
+    /// Atoms of `xyz_str` with the basis set `name` of `cint::library`, e.g. `def2-svp`.
+    #[cfg(feature = "basis-library")]
+    pub fn from_xyz_named(xyz_str: &str, name: &str) -> Result<Self, CintError> {
+        let spec = BasisSpec::new(BasisSource::Named(name.to_string()));
+        Self::fron_xyz_spec(xyz_str, &spec, Normalization::Primitive)
     }
 
-    pub fn fron_xyz(xyz_str: &str, basis_path: &str) -> Self {
-        let basis_str = fs::read_to_string(basis_path).expect("Error in reading the basis file");
-        let json: JsonBasis =
-            serde_json::from_str(&basis_str).expect("read basis from json failed");
+    /// Atoms of `xyz_str` with the bases `spec` assigns them, files scaled by `norm`.
+    pub fn fron_xyz_spec(
+        xyz_str: &str,
+        spec: &BasisSpec,
+        norm: Normalization,
+    ) -> Result<Self, CintError> {
+        CintDate::new(CintAtomGroup::from_xyz_spec(xyz_str, spec, norm)?)
+    }
+
+    pub fn atom_groups(&self) -> &Vec<CintAtomGroup> {
+        &self.atom_groups
+    }
+
+    /// Basis set of every element, for `BasisFormat::write`. An element with several bases
+    /// keeps its first; ghost atoms read by `from_raw` have no element and are left out.
+    pub fn basis(&self) -> BasisMap {
+        let mut basis = BasisMap::new();
+        for atoms in &self.atom_groups {
+            if let (Some(shells), 1..) = (atoms.basis(), *atoms.charge_of()) {
+                basis
+                    .entry(*atoms.charge_of())
+                    .or_insert_with(|| shells.clone());
+            }
+        }
+        basis
+    }
 
-        let mut atom_group = CintAtomGroup::from_xyz(xyz_str, None);
-        atom_group
+    /// Switch every atom to a Gaussian nucleus (`AtomGroup::use_gaussian_nuc`), the
+    /// nuclear attraction integrals of `Intor`s generated afterwards use it. Atoms with a
+    /// fractional charge stay point charges.
+    pub fn use_gaussian_nuc(&mut self) {
+        self.atom_groups
             .iter_mut()
-            .for_each(|atoms| match json.get_elements(*atoms.charge_of()) {
-                Some(ele) => (*atoms.basis_mut()) = Some(ele.to_cgto(0, true)),
-                None => panic!(""),
+            .for_each(|atoms| atoms.use_gaussian_nuc());
+        for (iatm, igroup) in self.atmbas_index.iter().enumerate() {
+            let atoms = &self.atom_groups[*igroup];
+            let atm = &mut self.atm[iatm];
+            atm.nuc_mod_of = (*atoms.nuc_mod_of()).into();
+            self.env.data[atm.zeta as usize] = *atoms.zeta();
+        }
+    }
+
+    /// Move atom `iatm` to `coord` (bohr). Only the env coordinates and the atom group
+    /// change. Optimizers are not refreshed: an `Intor` borrows this `CintDate` and keeps the
+    /// optimizers it built, so generate a new `Intor` after moving atoms.
+    pub fn set_atom_coord(&mut self, iatm: AtomIndex, coord: [f64; 3]) -> Result<(), CintError> {
+        self.check_atom(iatm)?;
+        let igroup = self.atmbas_index[iatm];
+        let first: usize = self.atom_groups[..igroup]
+            .iter()
+            .map(|atoms| atoms.coordinates().len())
+            .sum();
+        self.atom_groups[igroup].coordinates_mut()[iatm - first] = coord;
+        self.env.set_vec3(self.atm[iatm].coord as u32, coord);
+        Ok(())
+    }
+
+    /// Move every atom, `coords` is in atom order (bohr), see `set_atom_coord`.
+    pub fn set_coordinates(&mut self, coords: &[[f64; 3]]) -> Result<(), CintError> {
+        if coords.len() != self.atm.len() {
+            return Err(CintError::AtomCount {
+                expected: self.atm.len(),
+                found: coords.len(),
             });
+        }
+        let mut moved = coords.iter().zip(self.atm.iter());
+        for atoms in self.atom_groups.iter_mut() {
+            for (coord, (new, atom)) in atoms.coordinates_mut().iter_mut().zip(moved.by_ref()) {
+                *coord = *new;
+                self.env.set_vec3(atom.coord as u32, *new);
+            }
+        }
+        Ok(())
+    }
 
-        CintDate::new(atom_group)
+    /// Coordinates of every atom (bohr), read back from `env`.
+    pub fn coordinates(&self) -> Vec<[f64; 3]> {
+        self.atm
+            .iter()
+            .map(|atom| self.env.vec3(atom.coord as u32))
+            .collect()
     }
 
-    fn gen_bas_select(&self, which_bas: BTreeMap<AtomIndex, Vec<BasisIndex>>) -> Vec<CintBasis> {
+    /// Run `f` with the env globals changed by `set`, and restore them afterwards.
+    fn with_env<R>(&mut self, set: impl FnOnce(&mut CintEnv), f: impl FnOnce(&Self) -> R) -> R {
+        f(&GlobalsGuard::new(self, |data| &mut data.env, set))
+    }
+
+    /// Run `f` with the origin of the `rinv` operators at `origin` (an atom index or a point).
+    pub fn with_rinv_origin<R>(
+        &mut self,
+        origin: impl Into<Origin>,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Result<R, CintError> {
+        let orig = self.env.origin_coord(&self.atm, origin.into())?;
+        Ok(self.with_env(|env| env.set_rinv_orig(orig), f))
+    }
+
+    /// Run `f` with the common (gauge) origin at `origin`.
+    pub fn with_common_origin<R>(
+        &mut self,
+        origin: impl Into<Origin>,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Result<R, CintError> {
+        let orig = self.env.origin_coord(&self.atm, origin.into())?;
+        Ok(self.with_env(|env| env.set_common_orig(orig), f))
+    }
+
+    /// Run `f` with a Gaussian charge distribution of exponent `zeta` for the `rinv` operators.
+    pub fn with_rinv_zeta<R>(&mut self, zeta: f64, f: impl FnOnce(&Self) -> R) -> R {
+        self.with_env(|env| env.set_rinv_zeta(zeta), f)
+    }
+
+    /// Run `f` with range-separated Coulomb: `omega > 0` long range, `omega < 0` short range.
+    pub fn with_range_omega<R>(&mut self, omega: f64, f: impl FnOnce(&Self) -> R) -> R {
+        self.with_env(|env| env.set_range_omega(omega), f)
+    }
+
+    /// External charges seen by the `Intor`s generated afterwards, see
+    /// `Intor::embedding_potential` and `Intor::eval_nuc`.
+    pub fn set_point_charges(&mut self, point_charges: Vec<PointCharge>) {
+        self.point_charges = point_charges;
+    }
+
+    pub fn point_charges(&self) -> &Vec<PointCharge> {
+        &self.point_charges
+    }
+
+    /// Whether atom `iatm` is a ghost, a basis-only centre without nuclear charge.
+    pub fn is_ghost(&self, iatm: AtomIndex) -> Result<bool, CintError> {
+        self.check_atom(iatm)?;
+        Ok(*self.atom_groups[self.atmbas_index[iatm]].ghost())
+    }
+
+    /// Gaussian nuclear exponent of atom `iatm`, read back from `env`.
+    pub fn zeta(&self, iatm: AtomIndex) -> Result<f64, CintError> {
+        self.check_atom(iatm)?;
+        Ok(self.env.data[self.atm[iatm].zeta as usize])
+    }
+
+    /// Fractional nuclear charge of atom `iatm`, read back from `env`. libcint uses it
+    /// instead of `charge_of` when the atom's `nuc_mod_of` is `NUC_FRAC_CHARGE`.
+    pub fn frac_charge(&self, iatm: AtomIndex) -> Result<f64, CintError> {
+        self.check_atom(iatm)?;
+        Ok(self.env.data[self.atm[iatm].frac_charge as usize])
+    }
+
+    /// Versioned JSON checkpoint of the whole molecule, geometry, basis and env layout.
+    pub fn to_json(&self) -> Result<String, CintError> {
+        let checkpoint = Checkpoint {
+            version: CHECKPOINT_VERSION,
+            cint_date: self,
+        };
+        serde_json::to_string(&checkpoint).map_err(|why| CintError::Checkpoint {
+            msg: why.to_string(),
+        })
+    }
+
+    /// Reload a checkpoint of `to_json`, its env pointers are checked before any use.
+    pub fn from_json(json: &str) -> Result<Self, CintError> {
+        let json_err = |why: serde_json::Error| CintError::Checkpoint {
+            msg: why.to_string(),
+        };
+        let value: serde_json::Value = serde_json::from_str(json).map_err(json_err)?;
+        match value.get("version").and_then(|version| version.as_u64()) {
+            Some(version) if version == CHECKPOINT_VERSION as u64 => {}
+            found => {
+                return Err(CintError::CheckpointVersion {
+                    found,
+                    supported: CHECKPOINT_VERSION,
+                })
+            }
+        }
+        let checkpoint: Checkpoint<CintDate> = serde_json::from_value(value).map_err(json_err)?;
+        checkpoint.cint_date.validate()?;
+        Ok(checkpoint.cint_date)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), CintError> {
+        fs::write(path, self.to_json()?).map_err(|why| CintError::Checkpoint {
+            msg: format!("{}: {}", path, why),
+        })
+    }
+
+    pub fn load(path: &str) -> Result<Self, CintError> {
+        let json = fs::read_to_string(path).map_err(|why| CintError::Checkpoint {
+            msg: format!("{}: {}", path, why),
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// Plain `atm`, `bas` and `env` arrays as in PySCF's `mol._atm`, `mol._bas` and
+    /// `mol._env`. Point charges are not part of them.
+    pub fn to_raw(&self) -> (Vec<i32>, Vec<i32>, Vec<f64>) {
+        let atm = self
+            .atm
+            .iter()
+            .flat_map(|atom| {
+                [
+                    atom.charge_of,
+                    atom.coord,
+                    atom.nuc_mod_of,
+                    atom.zeta,
+                    atom.frac_charge,
+                    atom.reserve_atmslot,
+                ]
+            })
+            .collect();
+        let bas = self
+            .gen_bas_all()
+            .iter()
+            .flat_map(|bas| {
+                [
+                    bas.atom_of,
+                    bas.ang_of,
+                    bas.nprim_of,
+                    bas.nctr_of,
+                    bas.kappa_of,
+                    bas.ptr_exp,
+                    bas.ptr_coeff,
+                    bas.reserve_baslot,
+                ]
+            })
+            .collect();
+        (atm, bas, self.env.data.clone())
+    }
+
+    /// Rebuild a molecule from PySCF-style `atm`, `bas` and `env` arrays. Consecutive atoms
+    /// with the same element, nuclear model and shells share a group, so the atom order is
+    /// kept; the shells of every atom are taken in `bas` order. Atoms of charge 0 are ghosts.
+    pub fn from_raw(atm: &[i32], bas: &[i32], env: &[f64]) -> Result<Self, CintError> {
+        let raw_err = |msg: String| CintError::Raw { msg };
+        let (atm_slots, bas_slots) = (ATM_SLOTS as usize, BAS_SLOTS as usize);
+        if !atm.len().is_multiple_of(atm_slots) || !bas.len().is_multiple_of(bas_slots) {
+            return Err(raw_err("array length is not a multiple of the slots".to_string()));
+        }
+        if env.len() < PTR_ENV_START as usize {
+            return Err(raw_err("env is shorter than its global parameters".to_string()));
+        }
+        let natm = atm.len() / atm_slots;
+        let env_slice = |ptr: i32, n: usize| {
+            usize::try_from(ptr)
+                .ok()
+                .and_then(|ptr| env.get(ptr..ptr + n))
+                .ok_or_else(|| raw_err(format!("pointer {} outside of env", ptr)))
+        };
+
+        let mut basis: Vec<Vec<CGTO>> = vec![Vec::new(); natm];
+        for bas in bas.chunks_exact(bas_slots) {
+            let (iatm, angl, nprim, nctr) = (bas[0], bas[1], bas[2], bas[3]);
+            let atom_basis = usize::try_from(iatm)
+                .ok()
+                .and_then(|iatm| basis.get_mut(iatm))
+                .ok_or_else(|| raw_err(format!("shell on missing atom {}", iatm)))?;
+            if !(0..ANG_MAX as i32).contains(&angl) || nprim < 1 || nctr < 1 {
+                return Err(raw_err(format!("invalid shell {:?}", bas)));
+            }
+            let (nprim, nctr) = (nprim as usize, nctr as usize);
+            atom_basis.push(CGTO {
+                kappa_of: bas[4] as i8,
+                angl: vec![angl as u8],
+                exp: env_slice(bas[5], nprim)?.to_vec(),
+                coeff: env_slice(bas[6], nprim * nctr)?
+                    .chunks(nprim)
+                    .map(|coeff| coeff.to_vec())
+                    .collect(),
+            });
+        }
+
+        let mut atom_groups: Vec<CintAtomGroup> = Vec::new();
+        for (atom, basis) in atm.chunks_exact(atm_slots).zip(basis) {
+            let charge_of = u8::try_from(atom[0])
+                .map_err(|_| raw_err(format!("invalid nuclear charge {}", atom[0])))?;
+            let coord = env_slice(atom[1], 3)?;
+            let nuc_mod_of = u8::try_from(atom[2])
+                .map_err(|_| raw_err(format!("invalid nuclear model {}", atom[2])))?;
+            let zeta = match nuc_mod_of as i32 {
+                NUC_GAUSS => env_slice(atom[3], 1)?[0],
+                _ => 0.0,
+            };
+            let frac_charge = match nuc_mod_of as i32 {
+                NUC_FRAC_CHARGE => env_slice(atom[4], 1)?[0],
+                _ => 0.0,
+            };
+            let mut group = CintAtomGroup::new(
+                Some(basis),
+                charge_of,
+                nuc_mod_of,
+                zeta,
+                frac_charge,
+                vec![[coord[0], coord[1], coord[2]]],
+            );
+            *group.ghost_mut() = charge_of == 0;
+
+            match atom_groups.last_mut() {
+                Some(last)
+                    if last.basis() == group.basis()
+                        && (last.charge_of(), last.nuc_mod_of(), last.ghost())
+                            == (group.charge_of(), group.nuc_mod_of(), group.ghost())
+                        && (last.zeta(), last.frac_charge())
+                            == (group.zeta(), group.frac_charge()) =>
+                {
+                    last.coordinates_mut().push(group.coordinates()[0])
+                }
+                _ => atom_groups.push(group),
+            }
+        }
+
+        // the grid slots point into the old env, so copy the grids over to the new one
+        let grids: Vec<[f64; 3]> = match env[NGRIDS as usize] as usize {
+            0 => Vec::new(),
+            ngrids => env_slice(env[PTR_GRIDS as usize] as i32, 3 * ngrids)?
+                .chunks(3)
+                .map(|x| [x[0], x[1], x[2]])
+                .collect(),
+        };
+        let mut cint_data = CintDate::new(atom_groups)?;
+        cint_data
+            .env
+            .restore_globals(&env[..PTR_ENV_START as usize]);
+        cint_data.env.set_grids(&grids);
+        Ok(cint_data)
+    }
+
+    /// `from_raw` with the arrays saved by `numpy.save(path, mol._atm)` and so on.
+    pub fn from_npy(atm_path: &str, bas_path: &str, env_path: &str) -> Result<Self, CintError> {
+        let atm = npy::read_i32(atm_path)?;
+        let bas = npy::read_i32(bas_path)?;
+        let env = npy::read_f64(env_path)?;
+        Self::from_raw(&atm, &bas, &env)
+    }
+
+    /// Check that the arrays are consistent and every pointer lies inside `env`.
+    fn validate(&self) -> Result<(), CintError> {
+        let corrupt = |msg: &str| {
+            Err(CintError::Checkpoint {
+                msg: msg.to_string(),
+            })
+        };
+        let len = self.env.data.len();
+        let in_env = |ptr: i32, n: i32| {
+            ptr >= 0 && n >= 0 && ptr.checked_add(n).is_some_and(|end| end as usize <= len)
+        };
+
+        let ngroup = self.atom_groups.len();
+        if self.basis_template.len() != ngroup
+            || self.atmbas_index.len() != self.atm.len()
+            || self.atmbas_index.iter().any(|igroup| *igroup >= ngroup)
+        {
+            return corrupt("atoms do not match their groups");
+        }
+        let group_of_atoms = self
+            .atom_groups
+            .iter()
+            .enumerate()
+            .flat_map(|(igroup, atoms)| std::iter::repeat_n(igroup, atoms.coordinates().len()));
+        if !group_of_atoms.eq(self.atmbas_index.iter().copied()) {
+            return corrupt("atom coordinates do not match their groups");
+        }
+        if len < PTR_ENV_START as usize {
+            return corrupt("env is shorter than its global parameters");
+        }
+        for atom in &self.atm {
+            if !(in_env(atom.coord, 3) && in_env(atom.zeta, 1) && in_env(atom.frac_charge, 1)) {
+                return corrupt("atom pointer outside of env");
+            }
+        }
+        for bas in self.basis_template.iter().flatten() {
+            if !(0..ANG_MAX as i32).contains(&bas.ang_of) || bas.nprim_of < 1 || bas.nctr_of < 1 {
+                return corrupt("invalid shell");
+            }
+            if !(in_env(bas.ptr_exp, bas.nprim_of)
+                && bas
+                    .nprim_of
+                    .checked_mul(bas.nctr_of)
+                    .is_some_and(|n| in_env(bas.ptr_coeff, n)))
+            {
+                return corrupt("shell pointer outside of env");
+            }
+        }
+        Ok(())
+    }
+
+    fn check_atom(&self, iatm: AtomIndex) -> Result<(), CintError> {
+        match iatm < self.atm.len() {
+            true => Ok(()),
+            false => Err(CintError::AtomOutOfRange {
+                atom: iatm,
+                natm: self.atm.len(),
+            }),
+        }
+    }
+
+    /// Index of the first shell of every atom in `gen_bas_all` order.
+    fn shl_offset(&self) -> Vec<BasisIndex> {
+        let mut offset = 0;
+        self.atmbas_index
+            .iter()
+            .map(|ibas| {
+                let start = offset;
+                offset += self.basis_template[*ibas].len();
+                start
+            })
+            .collect()
+    }
+
+    fn gen_bas_select(
+        &self,
+        which_bas: BTreeMap<AtomIndex, Vec<BasisIndex>>,
+    ) -> (Vec<CintBasis>, Vec<BasisIndex>) {
+        let shl_offset = self.shl_offset();
         which_bas
             .iter()
             .flat_map(|(iatm, ibas_all)| {
-                ibas_all.iter().flat_map(|ibas| {
-                    self.basis_template[*ibas]
-                        .iter()
-                        .map(|bas| {
-                            let mut rbas = bas.clone();
-                            rbas.atom_of = *iatm as i32;
-                            rbas
-                        })
-                        .collect::<Vec<CintBasis>>()
+                let template = &self.basis_template[self.atmbas_index[*iatm]];
+                let shl_offset = &shl_offset;
+                ibas_all.iter().map(move |ibas| {
+                    let mut rbas = template[*ibas];
+                    rbas.atom_of = *iatm as i32;
+                    (rbas, shl_offset[*iatm] + ibas)
                 })
             })
-            .collect()
+            .unzip()
     }
 
-    fn gen_bas(&self, iatm: Vec<AtomIndex>) -> Vec<CintBasis> {
+    fn gen_bas(&self, iatm: Vec<AtomIndex>) -> (Vec<CintBasis>, Vec<BasisIndex>) {
+        let shl_offset = self.shl_offset();
         iatm.iter()
-            .map(|i| self.atmbas_index[*i])
-            .enumerate()
-            .flat_map(|(iatm, ibas)| {
-                let i = &iatm;
-                self.basis_template[ibas]
+            .flat_map(|i| {
+                let shl_offset = &shl_offset;
+                self.basis_template[self.atmbas_index[*i]]
                     .iter()
-                    .map(|bas| {
-                        let mut rbas = bas.clone();
+                    .enumerate()
+                    .map(move |(ibas, bas)| {
+                        let mut rbas = *bas;
                         rbas.atom_of = *i as i32;
-                        rbas
+                        (rbas, shl_offset[*i] + ibas)
                     })
-                    .collect::<Vec<CintBasis>>()
             })
-            .collect()
+            .unzip()
     }
 
     fn gen_bas_all(&self) -> Vec<CintBasis> {
@@ -304,18 +966,80 @@ impl CintDate {
             .collect()
     }
 
-    pub fn gen_intor_select<'a>(
-        &'a self,
+    /// Auxiliary `CintDate` on the same atoms for density fitting, every group with the
+    /// even-tempered basis `auxbasis::aug_etb` builds from its orbital basis.
+    pub fn aux_etb(&self, beta: f64) -> Result<Self, CintError> {
+        let atom_groups: Vec<CintAtomGroup> = self
+            .atom_groups
+            .iter()
+            .map(|atoms| {
+                let shells = atoms.basis().as_deref().unwrap_or_default();
+                let aux = aug_etb(*atoms.charge_of(), shells, beta, Normalization::Primitive)?;
+                let mut aux_atoms = atoms.clone();
+                *aux_atoms.basis_mut() = Some(aux);
+                Ok(aux_atoms)
+            })
+            .collect::<Result<_, CintError>>()?;
+        let mut aux = CintDate::new(atom_groups)?;
+        aux.point_charges = self.point_charges.clone();
+        aux.env.restore_globals(self.env.globals());
+        Ok(aux)
+    }
+
+    /// Atoms of `self` followed by those of `aux` as point-nucleus ghosts, so the nuclear
+    /// attraction is unchanged. The `nbas` shells of `self` come first, `int3c2e` over
+    /// `[[0, nbas], [0, nbas], [nbas, nbas + naux]]` gives the 3-centre density fitting
+    /// integrals and `int2c2e` over the last `naux` shells the 2-centre ones.
+    pub fn with_aux(&self, aux: &CintDate) -> Result<Self, CintError> {
+        let ghosts = aux.atom_groups.iter().cloned().map(|mut atoms| {
+            *atoms.ghost_mut() = true;
+            *atoms.nuc_mod_of_mut() = NUC_POINT as u8;
+            atoms
+        });
+        let atom_groups: Vec<CintAtomGroup> =
+            self.atom_groups.iter().cloned().chain(ghosts).collect();
+        let mut combined = CintDate::new(atom_groups)?;
+        combined.point_charges = self.point_charges.clone();
+        combined.env.restore_globals(self.env.globals());
+        Ok(combined)
+    }
+
+    /// Intor over the shells `which_bas[iatm]` of each listed atom, indexed in that atom's
+    /// basis. All atoms keep their nuclei, so the results are sub-blocks of the full ones.
+    pub fn gen_intor_select(
+        &self,
         which_bas: BTreeMap<AtomIndex, Vec<BasisIndex>>,
-    ) -> Intor {
-        Intor::new(&self.atm, self.gen_bas_all(), &self.env)
+    ) -> Result<Intor<'_>, CintError> {
+        for (iatm, ibas_all) in &which_bas {
+            self.check_atom(*iatm)?;
+            let nshl = self.basis_template[self.atmbas_index[*iatm]].len();
+            if let Some(ibas) = ibas_all.iter().find(|ibas| **ibas >= nshl) {
+                return Err(CintError::AtomShellOutOfRange {
+                    atom: *iatm,
+                    shell: *ibas,
+                    nshl,
+                });
+            }
+        }
+        let (bas, shl_index) = self.gen_bas_select(which_bas);
+        Ok(Intor::new(&self.atm, bas, &self.env)
+            .with_shl_index(shl_index)
+            .with_point_charges(&self.point_charges))
     }
 
-    pub fn gen_intor<'a>(&'a self, iatm: Vec<AtomIndex>) -> Intor {
-        Intor::new(&self.atm, self.gen_bas_all(), &self.env)
+    /// Intor over all shells of the atoms `iatm`, in the given order.
+    pub fn gen_intor(&self, iatm: Vec<AtomIndex>) -> Result<Intor<'_>, CintError> {
+        for i in &iatm {
+            self.check_atom(*i)?;
+        }
+        let (bas, shl_index) = self.gen_bas(iatm);
+        Ok(Intor::new(&self.atm, bas, &self.env)
+            .with_shl_index(shl_index)
+            .with_point_charges(&self.point_charges))
     }
 
-    pub fn gen_intor_all<'a>(&'a self) -> Intor {
+    pub fn gen_intor_all(&self) -> Intor<'_> {
         Intor::new(&self.atm, self.gen_bas_all(), &self.env)
+            .with_point_charges(&self.point_charges)
     }
 }
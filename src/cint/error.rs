@@ -0,0 +1,246 @@
+use core::io::PERIODIC_TABLE;
+use std::fmt;
+
+use super::{intor::Symmetry, AtomIndex, BasisIndex, CintType, NAtom, NBasis};
+
+/// Errors of building `CintDate` and evaluating integrals. Lines of xyz input count from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CintError {
+    Xyz {
+        line: usize,
+        msg: String,
+    },
+    UnknownElement {
+        line: usize,
+        symbol: String,
+    },
+    AtomCount {
+        expected: NAtom,
+        found: NAtom,
+    },
+    MissingBasis {
+        charge: u8,
+    },
+    AngularMomentum {
+        charge: u8,
+        angl: u8,
+    },
+    Contraction {
+        charge: u8,
+        ishl: usize,
+    },
+    BasisFile {
+        path: String,
+        msg: String,
+    },
+    BasisValue {
+        value: String,
+    },
+    BasisSyntax {
+        line: usize,
+        msg: String,
+    },
+    BasisExport {
+        msg: String,
+    },
+    UnknownBasis {
+        name: String,
+    },
+    AtomOutOfRange {
+        atom: AtomIndex,
+        natm: NAtom,
+    },
+    AtomShellOutOfRange {
+        atom: AtomIndex,
+        shell: BasisIndex,
+        nshl: NBasis,
+    },
+    ShellOutOfRange {
+        shell: i32,
+        nbas: i32,
+    },
+    ShellSlice {
+        slice: [i32; 2],
+        nbas: i32,
+    },
+    OutputTooSmall {
+        len: usize,
+        required: usize,
+    },
+    NullFunction,
+    UnknownIntegral {
+        name: String,
+        similar: Vec<&'static str>,
+    },
+    MissingForm {
+        name: &'static str,
+        cint_type: CintType,
+    },
+    CintTypeMismatch {
+        name: String,
+        suffix: CintType,
+        requested: CintType,
+    },
+    Arity {
+        name: String,
+        ncenter: NAtom,
+        requested: NAtom,
+    },
+    Spinor {
+        name: String,
+    },
+    NotHermitian {
+        name: &'static str,
+    },
+    Symmetry {
+        sym: Symmetry,
+        requested: NAtom,
+    },
+    SpinorPacked,
+    Checkpoint {
+        msg: String,
+    },
+    Raw {
+        msg: String,
+    },
+    Npy {
+        path: String,
+        msg: String,
+    },
+    CheckpointVersion {
+        found: Option<u64>,
+        supported: u32,
+    },
+    EtbBeta {
+        beta: f64,
+    },
+}
+
+/// Element symbol of nuclear charge `charge`, `?` when it is out of the periodic table.
+fn symbol(charge: u8) -> &'static str {
+    (charge as usize)
+        .checked_sub(1)
+        .and_then(|i| PERIODIC_TABLE.get(i))
+        .copied()
+        .unwrap_or("?")
+}
+
+impl fmt::Display for CintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CintError::Xyz { line, msg } => write!(f, "xyz line {}: {}", line, msg),
+            CintError::UnknownElement { line, symbol } => {
+                write!(f, "xyz line {}: unknown element `{}`", line, symbol)
+            }
+            CintError::AtomCount { expected, found } => write!(
+                f,
+                "xyz header declares {} atoms but {} are listed",
+                expected, found
+            ),
+            CintError::MissingBasis { charge } => {
+                write!(f, "no basis set for element {}", symbol(*charge))
+            }
+            CintError::AngularMomentum { charge, angl } => write!(
+                f,
+                "angular momentum {} of element {} is not supported by libcint",
+                angl,
+                symbol(*charge)
+            ),
+            CintError::Contraction { charge, ishl } => write!(
+                f,
+                "shell {} of element {} has coefficients that do not match its exponents",
+                ishl,
+                symbol(*charge)
+            ),
+            CintError::BasisFile { path, msg } => {
+                write!(f, "cannot read basis file {}: {}", path, msg)
+            }
+            CintError::BasisValue { value } => write!(f, "bad number `{}` in basis set", value),
+            CintError::BasisSyntax { line, msg } => write!(f, "basis line {}: {}", line, msg),
+            CintError::BasisExport { msg } => write!(f, "cannot write basis set: {}", msg),
+            CintError::UnknownBasis { name } => {
+                write!(f, "no basis set `{}` in the built-in library", name)
+            }
+            CintError::AtomOutOfRange { atom, natm } => {
+                write!(f, "atom {} out of range 0..{}", atom, natm)
+            }
+            CintError::AtomShellOutOfRange { atom, shell, nshl } => write!(
+                f,
+                "shell {} of atom {} out of range 0..{}",
+                shell, atom, nshl
+            ),
+            CintError::ShellOutOfRange { shell, nbas } => {
+                write!(f, "shell {} out of range 0..{}", shell, nbas)
+            }
+            CintError::ShellSlice { slice, nbas } => write!(
+                f,
+                "shell slice {}..{} out of range 0..{}",
+                slice[0], slice[1], nbas
+            ),
+            CintError::OutputTooSmall { len, required } => write!(
+                f,
+                "output buffer holds {} elements, {} are required",
+                len, required
+            ),
+            CintError::NullFunction => write!(f, "integral or optimizer function is None"),
+            CintError::UnknownIntegral { name, similar } => write!(
+                f,
+                "unknown integral `{}`, the closest bound ones are: {}",
+                name,
+                similar.join(", ")
+            ),
+            CintError::MissingForm { name, cint_type } => {
+                write!(f, "{} has no {:?} form", name, cint_type)
+            }
+            CintError::CintTypeMismatch {
+                name,
+                suffix,
+                requested,
+            } => write!(
+                f,
+                "{} is a {:?} integral but {:?} was requested",
+                name, suffix, requested
+            ),
+            CintError::Arity {
+                name,
+                ncenter,
+                requested,
+            } => write!(
+                f,
+                "{} is a {}-centre integral, not {}-centre",
+                name, ncenter, requested
+            ),
+            CintError::Spinor { name } => write!(
+                f,
+                "{} as spinor is complex, use the spinor evaluators",
+                name
+            ),
+            CintError::NotHermitian { name } => write!(f, "{} cannot be packed", name),
+            CintError::Symmetry { sym, requested } => {
+                write!(
+                    f,
+                    "{:?} does not apply to {}-centre integrals",
+                    sym, requested
+                )
+            }
+            CintError::SpinorPacked => {
+                write!(f, "symmetry packing is only defined for real integrals")
+            }
+            CintError::Checkpoint { msg } => write!(f, "invalid checkpoint: {}", msg),
+            CintError::Raw { msg } => write!(f, "invalid atm/bas/env arrays: {}", msg),
+            CintError::Npy { path, msg } => write!(f, "cannot read {}: {}", path, msg),
+            CintError::CheckpointVersion { found, supported } => write!(
+                f,
+                "checkpoint version {:?} is not supported, expected {}",
+                found, supported
+            ),
+            CintError::EtbBeta { beta } => write!(
+                f,
+                "even-tempered exponent ratio {} must be greater than 1",
+                beta
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CintError {}
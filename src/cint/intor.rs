@@ -1,42 +1,227 @@
-use std::ops::Index;
+use std::{borrow::Cow, cell::RefCell, ops::Index, sync::Arc};
 
 use super::{
-    cdata::{CintAtom, CintBasis, CintEnv},
-    libcint::{CINTIntegralFunction, CINTcgtos_cart, CINTcgtos_spheric, CINTcgtos_spinor},
-    NAtom,
+    cdata::{charge_shells, CintAtom, CintBasis, CintEnv, GlobalsGuard, Origin, PointCharge},
+    error::CintError,
+    kind::{Hermiticity, IntegralKind},
+    libcint::{
+        __BindgenComplex, CINTIntegralFunction, CINTcgtos_cart, CINTcgtos_spheric,
+        CINTcgtos_spinor, CINTshells_cart_offset, CINTshells_spheric_offset,
+        CINTshells_spinor_offset,
+    },
+    optimizer::{Optimizer, OptimizerCache},
+    registry::{INT1E_NUC, INT3C2E},
+    AtomIndex, BasisIndex, CintType, NAtom,
 };
 
+pub type Complex64 = __BindgenComplex<f64>;
+
+thread_local! {
+    static CACHE: RefCell<Vec<f64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f` with this thread's libcint cache, grown to at least `size` doubles.
+fn with_cache<R>(size: usize, f: impl FnOnce(*mut f64) -> R) -> R {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() < size {
+            cache.resize(size, 0.0);
+        }
+        f(cache.as_mut_ptr())
+    })
+}
+
+/// A raw libcint integral function and the number of components it writes per element.
+#[derive(Debug, Clone, Copy)]
+pub struct IntorFunc {
+    pub func: CINTIntegralFunction,
+    pub ncomp: usize,
+}
+
+impl IntorFunc {
+    /// # Panics
+    ///
+    /// If `ncomp` is 0, an integral writes at least one component.
+    pub fn new(func: CINTIntegralFunction, ncomp: usize) -> Self {
+        assert!(ncomp > 0);
+        Self { func, ncomp }
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct IntorResult<const N: NAtom> {
+pub struct IntorResult<const N: NAtom, T = f64> {
     dims: Vec<i32>,
-    out: Vec<f64>,
+    ncomp: usize,
+    out: Vec<T>,
 }
 
-impl<const N: NAtom> IntorResult<N> {
+impl<const N: NAtom, T> IntorResult<N, T> {
     pub fn dims(&self) -> &Vec<i32> {
         &self.dims
     }
 
-    pub fn out(&self) -> &Vec<f64> {
+    pub fn ncomp(&self) -> usize {
+        self.ncomp
+    }
+
+    pub fn out(&self) -> &Vec<T> {
         &self.out
     }
+
+    /// The AO tensor of one component.
+    ///
+    /// # Panics
+    ///
+    /// If `icomp` is not below `ncomp`.
+    pub fn comp(&self, icomp: usize) -> &[T] {
+        assert!(icomp < self.ncomp);
+        let size = self.out.len() / self.ncomp;
+        &self.out[icomp * size..(icomp + 1) * size]
+    }
 }
 
-impl<const N: NAtom> Index<Vec<usize>> for IntorResult<N> {
-    type Output = f64;
+/// Index by the `N` AO indices, optionally followed by the component (default 0).
+///
+/// # Panics
+///
+/// If the index has neither `N` nor `N + 1` entries, or any entry is out of range.
+impl<const N: NAtom, T> Index<Vec<usize>> for IntorResult<N, T> {
+    type Output = T;
 
     fn index(&self, index: Vec<usize>) -> &Self::Output {
-        assert_eq!(index.len(), N);
-        let out_i = index.last().unwrap()
-            + self.dims[1..N]
-                .iter()
-                .rev()
-                .enumerate()
-                .fold(0, |i, (p, x)| (p + index[i]) * (*x as usize));
+        assert!(index.len() == N || index.len() == N + 1);
+        // libcint layout: the first index runs fastest, the component slowest
+        let dims: Vec<usize> = self
+            .dims
+            .iter()
+            .map(|x| *x as usize)
+            .chain([self.ncomp])
+            .collect();
+        assert!(index.iter().zip(dims.iter()).all(|(i, x)| i < x));
+        let out_i = index
+            .iter()
+            .zip(dims.iter())
+            .rev()
+            .fold(0, |p, (i, x)| p * x + i);
         &self.out[out_i]
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    S2,
+    S4,
+    S8,
+}
+
+impl Symmetry {
+    fn ncenter(&self) -> usize {
+        match self {
+            Symmetry::S2 => 2,
+            Symmetry::S4 | Symmetry::S8 => 4,
+        }
+    }
+
+    fn packed_len(&self, nao: usize) -> usize {
+        let npair = pair_index(nao, 0);
+        match self {
+            Symmetry::S2 => npair,
+            Symmetry::S4 => npair * npair,
+            Symmetry::S8 => pair_index(npair, 0),
+        }
+    }
+}
+
+/// Position of `(i, j)` in a packed lower triangle, in either order.
+pub fn pair_index(i: usize, j: usize) -> usize {
+    if i >= j {
+        i * (i + 1) / 2 + j
+    } else {
+        j * (j + 1) / 2 + i
+    }
+}
+
+pub fn unpack_s2(packed: &[f64], nao: usize) -> Vec<f64> {
+    (0..nao)
+        .flat_map(|j| (0..nao).map(move |i| packed[pair_index(i, j)]))
+        .collect()
+}
+
+pub fn unpack_s4(packed: &[f64], nao: usize) -> Vec<f64> {
+    let npair = pair_index(nao, 0);
+    (0..nao.pow(4))
+        .map(|x| {
+            let [i, j, k, l] = [x % nao, x / nao % nao, x / nao.pow(2) % nao, x / nao.pow(3)];
+            packed[pair_index(i, j) + npair * pair_index(k, l)]
+        })
+        .collect()
+}
+
+pub fn unpack_s8(packed: &[f64], nao: usize) -> Vec<f64> {
+    (0..nao.pow(4))
+        .map(|x| {
+            let [i, j, k, l] = [x % nao, x / nao % nao, x / nao.pow(2) % nao, x / nao.pow(3)];
+            packed[pair_index(pair_index(i, j), pair_index(k, l))]
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct IntorPacked<const N: NAtom> {
+    sym: Symmetry,
+    nao: i32,
+    ncomp: usize,
+    out: Vec<f64>,
+}
+
+impl<const N: NAtom> IntorPacked<N> {
+    pub fn sym(&self) -> Symmetry {
+        self.sym
+    }
+
+    pub fn nao(&self) -> i32 {
+        self.nao
+    }
+
+    pub fn ncomp(&self) -> usize {
+        self.ncomp
+    }
+
+    /// Packed components one after another.
+    pub fn out(&self) -> &Vec<f64> {
+        &self.out
+    }
+
+    pub fn unpack(&self) -> IntorResult<N> {
+        let nao = self.nao as usize;
+        let out = self
+            .out
+            .chunks(self.sym.packed_len(nao))
+            .flat_map(|packed| match self.sym {
+                Symmetry::S2 => unpack_s2(packed, nao),
+                Symmetry::S4 => unpack_s4(packed, nao),
+                Symmetry::S8 => unpack_s8(packed, nao),
+            })
+            .collect();
+        IntorResult {
+            dims: vec![self.nao; N],
+            ncomp: self.ncomp,
+            out,
+        }
+    }
+}
+
+/// Integrals over the atoms, shells and env of a `CintDate`.
+///
+/// # Raw evaluators
+///
+/// The `unsafe` evaluators (`cache_size`, `int_*`, `int_*_into`, `intor_*`) call the libcint
+/// function of their `IntorFunc` as given. It must be the `_cart`, `_sph` or `_spinor` form the
+/// evaluator names (or its `cint_type`), of an integral with the evaluator's number of centres,
+/// hermitian for `intor_s2`/`s4`/`s8`, and `ncomp` must be its number of components. The
+/// optimizer set with `set_optimizer`, if any, must belong to that integral. libcint writes every
+/// shell block sized by these without bounds checks; the `*_kind` evaluators look all of this
+/// up in the registry and are safe.
 #[derive(Debug, Clone)]
 pub struct Intor<'a> {
     dims_cart: Vec<i32>,
@@ -46,7 +231,11 @@ pub struct Intor<'a> {
     atm: &'a Vec<CintAtom>,
     nbas: i32,
     bas: Vec<CintBasis>,
-    env: &'a CintEnv,
+    shl_index: Vec<BasisIndex>,
+    env: Cow<'a, CintEnv>,
+    opt: Option<Arc<Optimizer>>,
+    opts: OptimizerCache,
+    point_charges: &'a [PointCharge],
 }
 
 impl<'a> Intor<'a> {
@@ -73,8 +262,12 @@ impl<'a> Intor<'a> {
             natm: atm.len() as i32,
             atm,
             nbas: bas.len() as i32,
+            shl_index: (0..bas.len()).collect(),
             bas,
-            env,
+            env: Cow::Borrowed(env),
+            opt: None,
+            opts: OptimizerCache::default(),
+            point_charges: &[],
         }
     }
 
@@ -86,33 +279,887 @@ impl<'a> Intor<'a> {
         self.nbas
     }
 
+    /// Map each shell of this `Intor` to its index in the full molecule.
+    pub(super) fn with_shl_index(mut self, shl_index: Vec<BasisIndex>) -> Self {
+        assert_eq!(shl_index.len(), self.bas.len());
+        self.shl_index = shl_index;
+        self
+    }
+
+    pub(super) fn with_point_charges(mut self, point_charges: &'a [PointCharge]) -> Self {
+        self.point_charges = point_charges;
+        self
+    }
+
+    pub fn point_charges(&self) -> &[PointCharge] {
+        self.point_charges
+    }
+
+    /// Index of shell `ish` in the full molecule (`CintDate::gen_intor_all`).
+    pub fn global_shell(&self, ish: BasisIndex) -> Result<BasisIndex, CintError> {
+        self.shl_index
+            .get(ish)
+            .copied()
+            .ok_or(CintError::ShellOutOfRange {
+                shell: ish as i32,
+                nbas: self.nbas,
+            })
+    }
+
+    /// Atom that shell `ish` is centred on, in the full molecule.
+    pub fn shell_atom(&self, ish: BasisIndex) -> Result<AtomIndex, CintError> {
+        match self.bas.get(ish) {
+            Some(bas) => Ok(bas.atom_of as AtomIndex),
+            None => Err(CintError::ShellOutOfRange {
+                shell: ish as i32,
+                nbas: self.nbas,
+            }),
+        }
+    }
+
+    pub(super) fn atm_ptr(&self) -> *const i32 {
+        self.atm.as_ptr() as *const i32
+    }
+
+    pub(super) fn bas_ptr(&self) -> *const i32 {
+        self.bas.as_ptr() as *const i32
+    }
+
+    pub(super) fn env_ptr(&self) -> *const f64 {
+        self.env.as_ptr()
+    }
+
+    /// Run `f` with the env globals changed by `set`, and restore them afterwards. The first
+    /// scope copies the borrowed env into this `Intor`, later ones only swap the globals.
+    fn with_env<R>(&mut self, set: impl FnOnce(&mut CintEnv), f: impl FnOnce(&Intor) -> R) -> R {
+        f(&GlobalsGuard::new(self, |intor| intor.env.to_mut(), set))
+    }
+
+    /// Run `f` with the origin of the `rinv` operators at `origin` (an atom index or a point).
+    pub fn with_rinv_origin<R>(
+        &mut self,
+        origin: impl Into<Origin>,
+        f: impl FnOnce(&Intor) -> R,
+    ) -> Result<R, CintError> {
+        let orig = self.env.origin_coord(self.atm, origin.into())?;
+        Ok(self.with_env(|env| env.set_rinv_orig(orig), f))
+    }
+
+    /// Run `f` with the common (gauge) origin at `origin`.
+    pub fn with_common_origin<R>(
+        &mut self,
+        origin: impl Into<Origin>,
+        f: impl FnOnce(&Intor) -> R,
+    ) -> Result<R, CintError> {
+        let orig = self.env.origin_coord(self.atm, origin.into())?;
+        Ok(self.with_env(|env| env.set_common_orig(orig), f))
+    }
+
+    /// Run `f` with a Gaussian charge distribution of exponent `zeta` for the `rinv` operators.
+    pub fn with_rinv_zeta<R>(&mut self, zeta: f64, f: impl FnOnce(&Intor) -> R) -> R {
+        self.with_env(|env| env.set_rinv_zeta(zeta), f)
+    }
+
+    /// Run `f` with range-separated Coulomb: `omega > 0` long range, `omega < 0` short range.
+    pub fn with_range_omega<R>(&mut self, omega: f64, f: impl FnOnce(&Intor) -> R) -> R {
+        self.with_env(|env| env.set_range_omega(omega), f)
+    }
+
+    /// Pass the optimizer of `kind` to the raw evaluators (`int_sph`, `intor_sph`, ...) that
+    /// follow; the `*_kind` evaluators pick the optimizer of their own kind regardless.
+    pub fn set_optimizer(&mut self, kind: &IntegralKind) -> Result<(), CintError> {
+        self.opt = Some(self.opts.get(self, kind)?.ok_or(CintError::NullFunction)?);
+        Ok(())
+    }
+
+    /// Drop the optimizer of `set_optimizer` and every cached one.
+    pub fn clear_optimizer(&mut self) {
+        self.opt = None;
+        self.opts.clear();
+    }
+
+    pub fn optimizer(&self) -> Option<&Optimizer> {
+        self.opt.as_deref()
+    }
+
+    fn dims_shls(&self, cint_type: CintType) -> &Vec<i32> {
+        match cint_type {
+            CintType::Cartesian => &self.dims_cart,
+            CintType::Spheric => &self.dims_spheric,
+            CintType::Spinor => &self.dims_spinor,
+        }
+    }
+
+    /// AO offset of every shell, with the total number of AOs appended (`nbas + 1` entries).
+    pub fn ao_loc(&self, cint_type: CintType) -> Vec<i32> {
+        let nbas = self.nbas as usize;
+        let mut ao_loc = vec![0; nbas + 1];
+        let bas = self.bas.as_ptr() as *const i32;
+        unsafe {
+            match cint_type {
+                CintType::Cartesian => CINTshells_cart_offset(ao_loc.as_mut_ptr(), bas, self.nbas),
+                CintType::Spheric => CINTshells_spheric_offset(ao_loc.as_mut_ptr(), bas, self.nbas),
+                CintType::Spinor => CINTshells_spinor_offset(ao_loc.as_mut_ptr(), bas, self.nbas),
+            }
+        }
+        if nbas > 0 {
+            ao_loc[nbas] = ao_loc[nbas - 1] + self.dims_shls(cint_type)[nbas - 1];
+        }
+        ao_loc
+    }
+
+    pub fn nao(&self, cint_type: CintType) -> i32 {
+        *self.ao_loc(cint_type).last().unwrap()
+    }
+
+    /// Size (in doubles) of the libcint cache needed for `shls`.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn cache_size<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+    ) -> Result<usize, CintError> {
+        self.check_shls(&shls)?;
+        let size = unsafe {
+            self.call(
+                int_func.func,
+                self.optimizer(),
+                std::ptr::null_mut(),
+                std::ptr::null(),
+                shls.as_ptr(),
+                std::ptr::null_mut(),
+            )
+        }?;
+        Ok(size.max(0) as usize)
+    }
+
+    /// Largest cache over all shells, enough for any shell tuple of this basis.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn max_cache_size<const N: NAtom>(
+        &self,
+        int_func: IntorFunc,
+    ) -> Result<usize, CintError> {
+        (0..self.nbas).try_fold(0, |size, ish| {
+            Ok(size.max(unsafe { self.cache_size([ish; N], int_func) }?))
+        })
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
     pub unsafe fn int_cart<const N: NAtom>(
         &self,
         shls: [i32; N],
-        int_func: CINTIntegralFunction,
-    ) -> IntorResult<N> {
-        let dims: Vec<i32> = shls.iter().map(|l| self.dims_cart[*l as usize]).collect();
-        let mut out = vec![0.0; dims.iter().fold(1, |p, x| p * x) as usize];
-        match int_func {
-            Some(func) => {
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N>, CintError> {
+        unsafe {
+            self.eval_shls(
+                shls,
+                self.dims_shls(CintType::Cartesian),
+                int_func,
+                self.optimizer(),
+            )
+        }
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn int_sph<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N>, CintError> {
+        unsafe {
+            self.eval_shls(
+                shls,
+                self.dims_shls(CintType::Spheric),
+                int_func,
+                self.optimizer(),
+            )
+        }
+    }
+
+    /// Spinor integrals are complex, `out` holds `(re, im)` pairs in libcint order.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn int_spinor<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N, Complex64>, CintError> {
+        unsafe {
+            self.eval_shls(
+                shls,
+                self.dims_shls(CintType::Spinor),
+                int_func,
+                self.optimizer(),
+            )
+        }
+    }
+
+    /// Like `int_cart`, but writes into `out` instead of allocating; returns the block dims.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn int_cart_into<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+        out: &mut [f64],
+    ) -> Result<[i32; N], CintError> {
+        unsafe {
+            self.eval_shls_into(
+                shls,
+                self.dims_shls(CintType::Cartesian),
+                int_func,
+                self.optimizer(),
+                out,
+            )
+        }
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn int_sph_into<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+        out: &mut [f64],
+    ) -> Result<[i32; N], CintError> {
+        unsafe {
+            self.eval_shls_into(
+                shls,
+                self.dims_shls(CintType::Spheric),
+                int_func,
+                self.optimizer(),
+                out,
+            )
+        }
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn int_spinor_into<const N: NAtom>(
+        &self,
+        shls: [i32; N],
+        int_func: IntorFunc,
+        out: &mut [Complex64],
+    ) -> Result<[i32; N], CintError> {
+        unsafe {
+            self.eval_shls_into(
+                shls,
+                self.dims_shls(CintType::Spinor),
+                int_func,
+                self.optimizer(),
+                out,
+            )
+        }
+    }
+
+    /// Assemble the AO tensor over all shell tuples in `shls_slice` (default: every shell),
+    /// like `mol.intor` in PySCF.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_cart<const N: NAtom>(
+        &self,
+        shls_slice: Option<[[i32; 2]; N]>,
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N>, CintError> {
+        unsafe { self.assemble(CintType::Cartesian, shls_slice, int_func, self.optimizer()) }
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_sph<const N: NAtom>(
+        &self,
+        shls_slice: Option<[[i32; 2]; N]>,
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N>, CintError> {
+        unsafe { self.assemble(CintType::Spheric, shls_slice, int_func, self.optimizer()) }
+    }
+
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_spinor<const N: NAtom>(
+        &self,
+        shls_slice: Option<[[i32; 2]; N]>,
+        int_func: IntorFunc,
+    ) -> Result<IntorResult<N, Complex64>, CintError> {
+        unsafe { self.assemble(CintType::Spinor, shls_slice, int_func, self.optimizer()) }
+    }
+
+    /// Lower-triangle (`i >= j`) packed 1e matrix, only shell pairs `ish >= jsh` are computed.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_s2(
+        &self,
+        cint_type: CintType,
+        int_func: IntorFunc,
+    ) -> Result<IntorPacked<2>, CintError> {
+        unsafe { self.assemble_packed(cint_type, Symmetry::S2, int_func, self.optimizer()) }
+    }
+
+    /// ERIs packed as `(ij|kl)` with `i >= j` and `k >= l`.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_s4(
+        &self,
+        cint_type: CintType,
+        int_func: IntorFunc,
+    ) -> Result<IntorPacked<4>, CintError> {
+        unsafe { self.assemble_packed(cint_type, Symmetry::S4, int_func, self.optimizer()) }
+    }
+
+    /// ERIs packed as `(ij|kl)` with `i >= j`, `k >= l` and `ij >= kl`.
+    ///
+    /// # Safety
+    ///
+    /// See [the raw evaluators](Intor#raw-evaluators).
+    pub unsafe fn intor_s8(
+        &self,
+        cint_type: CintType,
+        int_func: IntorFunc,
+    ) -> Result<IntorPacked<4>, CintError> {
+        unsafe { self.assemble_packed(cint_type, Symmetry::S8, int_func, self.optimizer()) }
+    }
+
+    /// Optimizer for `kind`, built on its first evaluation and reused by all later ones.
+    fn kind_optimizer(&self, kind: &IntegralKind) -> Result<Option<Arc<Optimizer>>, CintError> {
+        self.opts.get(self, kind)
+    }
+
+    fn check_kind<const N: NAtom>(&self, kind: &IntegralKind) -> Result<(), CintError> {
+        match kind.ncenter == N {
+            true => Ok(()),
+            false => Err(CintError::Arity {
+                name: kind.name.to_string(),
+                ncenter: kind.ncenter,
+                requested: N,
+            }),
+        }
+    }
+
+    fn check_real(&self, kind: &IntegralKind, cint_type: CintType) -> Result<(), CintError> {
+        match cint_type {
+            CintType::Spinor => Err(CintError::Spinor {
+                name: kind.name.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_shls(&self, shls: &[i32]) -> Result<(), CintError> {
+        match shls.iter().find(|shl| !(0..self.nbas).contains(*shl)) {
+            Some(shl) => Err(CintError::ShellOutOfRange {
+                shell: *shl,
+                nbas: self.nbas,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Safe counterpart of `int_cart`/`int_sph`, for cartesian or spheric `cint_type`.
+    pub fn int_kind<const N: NAtom>(
+        &self,
+        kind: &IntegralKind,
+        cint_type: CintType,
+        shls: [i32; N],
+    ) -> Result<IntorResult<N>, CintError> {
+        self.check_real(kind, cint_type)?;
+        self.check_kind::<N>(kind)?;
+        let opt = self.kind_optimizer(kind)?;
+        let int_func = kind.func(cint_type)?;
+        unsafe { self.eval_shls(shls, self.dims_shls(cint_type), int_func, opt.as_deref()) }
+    }
+
+    pub fn int_kind_spinor<const N: NAtom>(
+        &self,
+        kind: &IntegralKind,
+        shls: [i32; N],
+    ) -> Result<IntorResult<N, Complex64>, CintError> {
+        self.check_kind::<N>(kind)?;
+        let opt = self.kind_optimizer(kind)?;
+        let int_func = kind.func(CintType::Spinor)?;
+        let dims_shls = self.dims_shls(CintType::Spinor);
+        unsafe { self.eval_shls(shls, dims_shls, int_func, opt.as_deref()) }
+    }
+
+    /// Safe counterpart of `intor_cart`/`intor_sph`.
+    pub fn intor_kind<const N: NAtom>(
+        &self,
+        kind: &IntegralKind,
+        cint_type: CintType,
+        shls_slice: Option<[[i32; 2]; N]>,
+    ) -> Result<IntorResult<N>, CintError> {
+        self.check_real(kind, cint_type)?;
+        self.check_kind::<N>(kind)?;
+        let opt = self.kind_optimizer(kind)?;
+        let int_func = kind.func(cint_type)?;
+        unsafe { self.assemble(cint_type, shls_slice, int_func, opt.as_deref()) }
+    }
+
+    pub fn intor_kind_spinor<const N: NAtom>(
+        &self,
+        kind: &IntegralKind,
+        shls_slice: Option<[[i32; 2]; N]>,
+    ) -> Result<IntorResult<N, Complex64>, CintError> {
+        self.check_kind::<N>(kind)?;
+        let opt = self.kind_optimizer(kind)?;
+        let int_func = kind.func(CintType::Spinor)?;
+        unsafe { self.assemble(CintType::Spinor, shls_slice, int_func, opt.as_deref()) }
+    }
+
+    /// Packed assembly of a hermitian `kind`; `Symmetry::S8` also assumes `(ij|kl) = (kl|ij)`.
+    pub fn intor_kind_packed<const N: NAtom>(
+        &self,
+        kind: &IntegralKind,
+        cint_type: CintType,
+        sym: Symmetry,
+    ) -> Result<IntorPacked<N>, CintError> {
+        self.check_kind::<N>(kind)?;
+        if kind.hermi != Hermiticity::Hermitian {
+            return Err(CintError::NotHermitian { name: kind.name });
+        }
+        let opt = self.kind_optimizer(kind)?;
+        let int_func = kind.func(cint_type)?;
+        unsafe { self.assemble_packed(cint_type, sym, int_func, opt.as_deref()) }
+    }
+
+    /// `kind`, a 3-centre Coulomb integral, between the AO pairs and the point charges,
+    /// contracted with `-q`: their one-electron potential for `INT3C2E`, its derivative on the
+    /// AO centres (like `int1e_ipnuc`) for `INT3C2E_IP1`. Each charge is a normalized s shell
+    /// (very compact for point charges), all of them are summed in one pass over the pairs.
+    pub fn intor_point_charges(
+        &self,
+        kind: &IntegralKind,
+        cint_type: CintType,
+    ) -> Result<IntorResult<2>, CintError> {
+        self.check_real(kind, cint_type)?;
+        self.check_kind::<3>(kind)?;
+        let int_func = kind.func(cint_type)?;
+        let ncomp = int_func.ncomp;
+        let ao_loc = self.ao_loc(cint_type);
+        let nao = ao_loc[self.nbas as usize] as usize;
+        let mut out = vec![0.0; nao * nao * ncomp];
+
+        if !self.point_charges.is_empty() {
+            let (atm, charge_bas, env) = charge_shells(self.atm, &self.env, self.point_charges);
+            let bas = [self.bas.as_slice(), &charge_bas].concat();
+            let intor = Intor::new(&atm, bas, &env);
+            let opt = intor.kind_optimizer(kind)?;
+            let dims_shls = self.dims_shls(cint_type);
+            let dmax = dims_shls.iter().copied().max().unwrap_or(0) as usize;
+            let mut buf = vec![0.0; dmax * dmax * ncomp];
+            let hermi = kind.hermi == Hermiticity::Hermitian;
+            let cache_size = unsafe { intor.max_cache_size::<3>(int_func) }?;
+            with_cache(cache_size, |cache| {
+                for ish in 0..self.nbas {
+                    let jsh_end = if hermi { ish + 1 } else { self.nbas };
+                    for jsh in 0..jsh_end {
+                        let (di, dj) = (dims_shls[ish as usize], dims_shls[jsh as usize]);
+                        let (di, dj) = (di as usize, dj as usize);
+                        let i0 = ao_loc[ish as usize] as usize;
+                        let j0 = ao_loc[jsh as usize] as usize;
+                        for (k, charge) in self.point_charges.iter().enumerate() {
+                            let shls = [ish, jsh, self.nbas + k as i32];
+                            unsafe {
+                                intor.call(
+                                    int_func.func,
+                                    opt.as_deref(),
+                                    buf.as_mut_ptr(),
+                                    std::ptr::null(),
+                                    shls.as_ptr(),
+                                    cache,
+                                )
+                            }?;
+                            for c in 0..ncomp {
+                                let out = &mut out[nao * nao * c..];
+                                for j in 0..dj {
+                                    for i in 0..di {
+                                        let v = -charge.charge * buf[i + di * (j + dj * c)];
+                                        out[i0 + i + nao * (j0 + j)] += v;
+                                        if hermi && ish != jsh {
+                                            out[j0 + j + nao * (i0 + i)] += v;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        Ok(IntorResult {
+            dims: vec![nao as i32; 2],
+            ncomp,
+            out,
+        })
+    }
+
+    /// `kind` of the `rinv` family (`INT1E_RINV`, `INT1E_IPRINV`, ...) summed over the point
+    /// charges with `-q`, the `rinv` origin on each charge and, for Gaussian charges, its
+    /// exponent as the `rinv` zeta. For `INT1E_RINV` this is `embedding_potential` again.
+    pub fn intor_rinv_point_charges(
+        &self,
+        kind: &IntegralKind,
+        cint_type: CintType,
+    ) -> Result<IntorResult<2>, CintError> {
+        self.check_real(kind, cint_type)?;
+        self.check_kind::<2>(kind)?;
+        let nao = self.nao(cint_type) as usize;
+        let mut out = vec![0.0; nao * nao * kind.ncomp];
+
+        // the charges are the atoms after ours, each one an origin for `with_rinv_origin`
+        let (atm, _, env) = charge_shells(self.atm, &self.env, self.point_charges);
+        let mut intor = Intor::new(&atm, self.bas.clone(), &env);
+        for (k, charge) in self.point_charges.iter().enumerate() {
+            intor.env.to_mut().set_rinv_zeta(charge.zeta);
+            let rinv = intor.with_rinv_origin(self.atm.len() + k, |intor| {
+                intor.intor_kind::<2>(kind, cint_type, None)
+            })??;
+            out.iter_mut()
+                .zip(rinv.out)
+                .for_each(|(x, y)| *x -= charge.charge * y);
+        }
+        Ok(IntorResult {
+            dims: vec![nao as i32; 2],
+            ncomp: kind.ncomp,
+            out,
+        })
+    }
+
+    /// One-electron potential of the point charges, the QM/MM embedding term of the core
+    /// Hamiltonian.
+    pub fn embedding_potential(&self, cint_type: CintType) -> Result<IntorResult<2>, CintError> {
+        self.intor_point_charges(&INT3C2E, cint_type)
+    }
+
+    /// `int1e_nuc`, plus the potential of the point charges if `with_charges`.
+    pub fn eval_nuc(
+        &self,
+        cint_type: CintType,
+        with_charges: bool,
+    ) -> Result<IntorResult<2>, CintError> {
+        let mut nuc = self.intor_kind::<2>(&INT1E_NUC, cint_type, None)?;
+        if with_charges {
+            let v = self.embedding_potential(cint_type)?;
+            nuc.out
+                .iter_mut()
+                .zip(v.out.iter())
+                .for_each(|(x, y)| *x += y);
+        }
+        Ok(nuc)
+    }
+
+    /// Assemble an integral by name, e.g. `intor.eval::<2>("int1e_nuc", CintType::Spheric)`.
+    pub fn eval<const N: NAtom>(
+        &self,
+        name: &str,
+        cint_type: CintType,
+    ) -> Result<IntorResult<N>, CintError> {
+        if cint_type == CintType::Spinor {
+            return Err(CintError::Spinor {
+                name: name.to_string(),
+            });
+        }
+        let kind = self.kind_by_name::<N>(name, cint_type)?;
+        self.intor_kind(kind, cint_type, None)
+    }
+
+    pub fn eval_spinor<const N: NAtom>(
+        &self,
+        name: &str,
+    ) -> Result<IntorResult<N, Complex64>, CintError> {
+        let kind = self.kind_by_name::<N>(name, CintType::Spinor)?;
+        self.intor_kind_spinor(kind, None)
+    }
+
+    fn kind_by_name<const N: NAtom>(
+        &self,
+        name: &str,
+        cint_type: CintType,
+    ) -> Result<&'static IntegralKind, CintError> {
+        let (kind, suffix_type) = IntegralKind::by_name(name)?;
+        match suffix_type {
+            Some(suffix_type) if suffix_type != cint_type => Err(CintError::CintTypeMismatch {
+                name: name.to_string(),
+                suffix: suffix_type,
+                requested: cint_type,
+            }),
+            _ if kind.ncenter != N => Err(CintError::Arity {
+                name: name.to_string(),
+                ncenter: kind.ncenter,
+                requested: N,
+            }),
+            _ => Ok(kind),
+        }
+    }
+
+    unsafe fn assemble<const N: NAtom, T: Clone + Default>(
+        &self,
+        cint_type: CintType,
+        shls_slice: Option<[[i32; 2]; N]>,
+        int_func: IntorFunc,
+        opt: Option<&Optimizer>,
+    ) -> Result<IntorResult<N, T>, CintError> {
+        let shls_slice = shls_slice.unwrap_or([[0, self.nbas]; N]);
+        if let Some(slice) = shls_slice
+            .iter()
+            .find(|[s0, s1]| !(0 <= *s0 && s0 <= s1 && *s1 <= self.nbas))
+        {
+            return Err(CintError::ShellSlice {
+                slice: *slice,
+                nbas: self.nbas,
+            });
+        }
+
+        let ao_loc = self.ao_loc(cint_type);
+        let dims: Vec<i32> = shls_slice
+            .iter()
+            .map(|[s0, s1]| ao_loc[*s1 as usize] - ao_loc[*s0 as usize])
+            .collect();
+        let size = dims.iter().product::<i32>() as usize;
+        let mut out = vec![T::default(); size * int_func.ncomp];
+
+        // blocks are written in place: `dims` gives libcint the strides of the full tensor
+        let nshls: Vec<usize> = shls_slice
+            .iter()
+            .map(|[s0, s1]| (s1 - s0) as usize)
+            .collect();
+        let nblk: usize = nshls.iter().product();
+        let mut shls = [0; N];
+        let cache_size = unsafe { self.max_cache_size::<N>(int_func) }?;
+        with_cache(cache_size, |cache| {
+            for iblk in 0..nblk {
+                let mut rest = iblk;
+                let mut offset = 0;
+                let mut stride = 1;
+                for k in 0..N {
+                    shls[k] = shls_slice[k][0] + (rest % nshls[k]) as i32;
+                    rest /= nshls[k];
+                    offset += (ao_loc[shls[k] as usize] - ao_loc[shls_slice[k][0] as usize])
+                        as usize
+                        * stride;
+                    stride *= dims[k] as usize;
+                }
                 unsafe {
-                    func(
-                        out.as_mut_ptr(),
+                    self.call(
+                        int_func.func,
+                        opt,
+                        out.as_mut_ptr().add(offset) as *mut f64,
                         dims.as_ptr(),
                         shls.as_ptr(),
-                        self.atm.as_ptr() as *const i32,
-                        self.natm,
-                        self.bas.as_ptr() as *const i32,
-                        self.nbas,
-                        self.env.as_ptr(),
-                        std::ptr::null(),
-                        std::ptr::null_mut(),
-                    );
+                        cache,
+                    )
+                }?;
+            }
+            Ok(())
+        })?;
+        Ok(IntorResult {
+            dims,
+            ncomp: int_func.ncomp,
+            out,
+        })
+    }
+
+    unsafe fn assemble_packed<const N: NAtom>(
+        &self,
+        cint_type: CintType,
+        sym: Symmetry,
+        int_func: IntorFunc,
+        opt: Option<&Optimizer>,
+    ) -> Result<IntorPacked<N>, CintError> {
+        if cint_type == CintType::Spinor {
+            return Err(CintError::SpinorPacked);
+        }
+        if N != sym.ncenter() {
+            return Err(CintError::Symmetry { sym, requested: N });
+        }
+
+        let ao_loc = self.ao_loc(cint_type);
+        let dims_shls = self.dims_shls(cint_type);
+        let nao = *ao_loc.last().unwrap();
+        let npair = pair_index(nao as usize, 0);
+        let ncomp = int_func.ncomp;
+        let packed_len = sym.packed_len(nao as usize);
+        let mut out = vec![0.0; packed_len * ncomp];
+
+        let dmax = dims_shls.iter().copied().max().unwrap_or(0) as usize;
+        let mut buf = vec![0.0; dmax.pow(N as u32) * ncomp];
+
+        let cache_size = unsafe { self.max_cache_size::<N>(int_func) }?;
+        let nbas = self.nbas;
+        let shl_pairs: Vec<[i32; 2]> = (0..nbas)
+            .flat_map(|ish| (0..=ish).map(move |jsh| [ish, jsh]))
+            .collect();
+        with_cache(cache_size, |cache| {
+            for (ij, [ish, jsh]) in shl_pairs.iter().enumerate() {
+                let kl_pairs = match sym {
+                    Symmetry::S2 => &shl_pairs[0..1],
+                    Symmetry::S4 => &shl_pairs[..],
+                    Symmetry::S8 => &shl_pairs[0..=ij],
+                };
+                for [ksh, lsh] in kl_pairs {
+                    let shls = [*ish, *jsh, *ksh, *lsh];
+                    let shls = &shls[0..N];
+                    let dims: Vec<i32> = shls.iter().map(|l| dims_shls[*l as usize]).collect();
+                    let loc: Vec<usize> =
+                        shls.iter().map(|l| ao_loc[*l as usize] as usize).collect();
+                    unsafe {
+                        self.call(
+                            int_func.func,
+                            opt,
+                            buf.as_mut_ptr(),
+                            dims.as_ptr(),
+                            shls.as_ptr(),
+                            cache,
+                        )
+                    }?;
+
+                    let [di, dj] = [dims[0] as usize, dims[1] as usize];
+                    let (dk, dl) = match N {
+                        4 => (dims[2] as usize, dims[3] as usize),
+                        _ => (1, 1),
+                    };
+                    let blk = di * dj * dk * dl;
+                    for c in 0..ncomp {
+                        let (buf, out) = (&buf[blk * c..], &mut out[packed_len * c..]);
+                        for l in 0..dl {
+                            for k in 0..dk {
+                                for j in 0..dj {
+                                    for i in 0..di {
+                                        let (p, q) = (loc[0] + i, loc[1] + j);
+                                        if p < q {
+                                            continue;
+                                        }
+                                        let v = buf[i + di * (j + dj * (k + dk * l))];
+                                        if let Symmetry::S2 = sym {
+                                            out[pair_index(p, q)] = v;
+                                            continue;
+                                        }
+                                        let (r, s) = (loc[2] + k, loc[3] + l);
+                                        if r < s {
+                                            continue;
+                                        }
+                                        let (pq, rs) = (pair_index(p, q), pair_index(r, s));
+                                        match sym {
+                                            Symmetry::S4 => out[pq + npair * rs] = v,
+                                            _ => out[pair_index(pq, rs)] = v,
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
-                ()
             }
-            None => panic!(""),
+            Ok(())
+        })?;
+        Ok(IntorPacked {
+            sym,
+            nao,
+            ncomp,
+            out,
+        })
+    }
+
+    unsafe fn eval_shls<const N: NAtom, T: Clone + Default>(
+        &self,
+        shls: [i32; N],
+        dims_shls: &[i32],
+        int_func: IntorFunc,
+        opt: Option<&Optimizer>,
+    ) -> Result<IntorResult<N, T>, CintError> {
+        self.check_shls(&shls)?;
+        let dims: Vec<i32> = shls.iter().map(|l| dims_shls[*l as usize]).collect();
+        let size = dims.iter().product::<i32>() as usize;
+        let mut out = vec![T::default(); size * int_func.ncomp];
+        unsafe { self.eval_shls_into(shls, dims_shls, int_func, opt, &mut out) }?;
+        Ok(IntorResult {
+            dims,
+            ncomp: int_func.ncomp,
+            out,
+        })
+    }
+
+    unsafe fn eval_shls_into<const N: NAtom, T>(
+        &self,
+        shls: [i32; N],
+        dims_shls: &[i32],
+        int_func: IntorFunc,
+        opt: Option<&Optimizer>,
+        out: &mut [T],
+    ) -> Result<[i32; N], CintError> {
+        self.check_shls(&shls)?;
+        let dims = shls.map(|l| dims_shls[l as usize]);
+        let required = dims.iter().product::<i32>() as usize * int_func.ncomp;
+        if out.len() < required {
+            return Err(CintError::OutputTooSmall {
+                len: out.len(),
+                required,
+            });
+        }
+        let cache_size = unsafe { self.cache_size(shls, int_func) }?;
+        with_cache(cache_size, |cache| unsafe {
+            self.call(
+                int_func.func,
+                opt,
+                out.as_mut_ptr() as *mut f64,
+                dims.as_ptr(),
+                shls.as_ptr(),
+                cache,
+            )
+        })?;
+        Ok(dims)
+    }
+
+    /// With a null `out`, libcint only returns the cache size it needs.
+    unsafe fn call(
+        &self,
+        int_func: CINTIntegralFunction,
+        opt: Option<&Optimizer>,
+        out: *mut f64,
+        dims: *const i32,
+        shls: *const i32,
+        cache: *mut f64,
+    ) -> Result<i32, CintError> {
+        match int_func {
+            Some(func) => Ok(unsafe {
+                func(
+                    out,
+                    dims,
+                    shls,
+                    self.atm_ptr(),
+                    self.natm,
+                    self.bas_ptr(),
+                    self.nbas,
+                    self.env_ptr(),
+                    opt.map_or(std::ptr::null(), |opt| opt.as_ptr()),
+                    cache,
+                )
+            }),
+            None => Err(CintError::NullFunction),
         }
-        IntorResult { dims, out }
     }
 }
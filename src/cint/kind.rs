@@ -0,0 +1,93 @@
+use super::{
+    error::CintError,
+    intor::IntorFunc,
+    libcint::{CINTIntegralFunction, CINTOptimizerFunction},
+    registry::INTEGRAL_KINDS,
+    CintType, NAtom,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hermiticity {
+    NonHermitian,
+    Hermitian,
+    AntiHermitian,
+}
+
+/// Everything needed to evaluate one libcint integral family safely, see `registry` for
+/// the entries of every bound integral.
+#[derive(Debug, Clone, Copy)]
+pub struct IntegralKind {
+    pub name: &'static str,
+    pub ncenter: NAtom,
+    pub ncomp: usize,
+    pub ncomp_spinor: usize,
+    pub hermi: Hermiticity,
+    pub cart: CINTIntegralFunction,
+    pub sph: CINTIntegralFunction,
+    pub spinor: CINTIntegralFunction,
+    pub optimizer: CINTOptimizerFunction,
+}
+
+impl IntegralKind {
+    /// Look up a PySCF-style name such as `"int1e_kin"`, `"int1e_kin_sph"` or `"int2e_ip1"`.
+    /// A `_cart`/`_sph`/`_spinor` suffix is returned as the requested `CintType`.
+    pub fn by_name(name: &str) -> Result<(&'static IntegralKind, Option<CintType>), CintError> {
+        let find = |name: &str| INTEGRAL_KINDS.iter().find(|kind| kind.name == name);
+        if let Some(kind) = find(name) {
+            return Ok((kind, None));
+        }
+        for (suffix, cint_type) in [
+            ("_cart", CintType::Cartesian),
+            ("_sph", CintType::Spheric),
+            ("_spinor", CintType::Spinor),
+        ] {
+            if let Some(kind) = name.strip_suffix(suffix).and_then(find) {
+                return Ok((kind, Some(cint_type)));
+            }
+        }
+
+        let mut similar: Vec<(usize, &str)> = INTEGRAL_KINDS
+            .iter()
+            .map(|kind| (edit_distance(name, kind.name), kind.name))
+            .collect();
+        similar.sort();
+        Err(CintError::UnknownIntegral {
+            name: name.to_string(),
+            similar: similar.iter().take(5).map(|(_, name)| *name).collect(),
+        })
+    }
+
+    pub fn func(&self, cint_type: CintType) -> Result<IntorFunc, CintError> {
+        let (func, ncomp) = match cint_type {
+            CintType::Cartesian => (self.cart, self.ncomp),
+            CintType::Spheric => (self.sph, self.ncomp),
+            CintType::Spinor => (self.spinor, self.ncomp_spinor),
+        };
+        match func {
+            Some(_) => Ok(IntorFunc::new(func, ncomp)),
+            None => Err(CintError::MissingForm {
+                name: self.name,
+                cint_type,
+            }),
+        }
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                prev.min(cur).min(row[j]) + 1
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,31 @@
+//! Common basis sets embedded at build time from the Basis Set Exchange (feature
+//! `basis-library`), looked up by name so that no basis set file is needed.
+
+use miniz_oxide::inflate::decompress_to_vec;
+
+use super::{
+    basis_format::{BasisFormat, BasisMap},
+    error::CintError,
+    rawdata::Normalization,
+};
+
+include!(concat!(env!("OUT_DIR"), "/basis_library.rs"));
+
+/// Names of the embedded basis sets, in lower case.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    LIBRARY.iter().map(|(name, _)| *name)
+}
+
+/// Embedded basis set `name` (`def2-svp`, `cc-pVDZ-JKFIT`, ...), in any case.
+pub fn basis(name: &str, norm: Normalization) -> Result<BasisMap, CintError> {
+    let lower = name.to_ascii_lowercase();
+    let (_, deflated) = LIBRARY
+        .iter()
+        .find(|(name, _)| *name == lower)
+        .ok_or_else(|| CintError::UnknownBasis {
+            name: name.to_string(),
+        })?;
+    let json = decompress_to_vec(deflated).expect("embedded basis sets are deflated at build");
+    let json = String::from_utf8(json).expect("embedded basis sets are UTF-8 JSON");
+    BasisFormat::BseJson.parse(&json, norm)
+}
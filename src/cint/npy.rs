@@ -0,0 +1,123 @@
+//! Reader of the `.npy` files written by `numpy.save`, enough for PySCF's `atm`, `bas` and
+//! `env` arrays: little-endian `int32`/`int64`/`float64`, C or 2-d Fortran order.
+
+use std::fs;
+
+use super::error::CintError;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+struct NpyArray<'a> {
+    descr: String,
+    shape: Vec<usize>,
+    fortran_order: bool,
+    data: &'a [u8],
+}
+
+/// Value of `key` in the python dict literal of an npy header, up to the next `,` or `)`
+/// (the closing `)` of a shape is included).
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}':", key))? + key.len() + 3;
+    let rest = header[start..].trim_start();
+    let end = match rest.starts_with('(') {
+        true => rest.find(')')? + 1,
+        false => rest.find([',', '}'])?,
+    };
+    Some(rest[..end].trim())
+}
+
+fn parse(bytes: &[u8]) -> Result<NpyArray<'_>, String> {
+    if bytes.len() < 10 || &bytes[..6] != MAGIC {
+        return Err("not an npy file".to_string());
+    }
+    let (header_len, start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => {
+            let len = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+            (len as usize, 12)
+        }
+        major => return Err(format!("unsupported npy version {}", major)),
+    };
+    let header = bytes
+        .get(start..start + header_len)
+        .ok_or("truncated header")?;
+    let header = std::str::from_utf8(header).map_err(|why| why.to_string())?;
+
+    let descr = header_value(header, "descr").ok_or("header without descr")?;
+    let fortran_order = header_value(header, "fortran_order").ok_or("header without order")?;
+    let shape = header_value(header, "shape").ok_or("header without shape")?;
+    let shape = shape
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().map_err(|_| format!("bad shape {}", shape)))
+        .collect::<Result<Vec<usize>, _>>()?;
+    Ok(NpyArray {
+        descr: descr.trim_matches(|c| c == '\'' || c == '"').to_string(),
+        shape,
+        fortran_order: fortran_order == "True",
+        data: &bytes[start + header_len..],
+    })
+}
+
+impl NpyArray<'_> {
+    /// Elements in C order, decoded from `size`-byte little-endian chunks.
+    fn values<T: Copy>(&self, size: usize, decode: impl Fn(&[u8]) -> T) -> Result<Vec<T>, String> {
+        let len: usize = self.shape.iter().product();
+        let data = self.data.get(..len * size).ok_or("truncated data")?;
+        let values: Vec<T> = data.chunks_exact(size).map(decode).collect();
+        match (self.fortran_order, self.shape.as_slice()) {
+            (false, _) => Ok(values),
+            (true, [_]) => Ok(values),
+            (true, [nrow, ncol]) => Ok((0..nrow * ncol)
+                .map(|i| values[i / ncol + nrow * (i % ncol)])
+                .collect()),
+            (true, _) => Err("Fortran order is only supported for 2-d arrays".to_string()),
+        }
+    }
+}
+
+fn read(path: &str) -> Result<Vec<u8>, CintError> {
+    fs::read(path).map_err(|why| CintError::Npy {
+        path: path.to_string(),
+        msg: why.to_string(),
+    })
+}
+
+/// Integer array such as `mol._atm` or `mol._bas`, flattened in C order.
+pub fn read_i32(path: &str) -> Result<Vec<i32>, CintError> {
+    let bytes = read(path)?;
+    let decode = || -> Result<Vec<i32>, String> {
+        let array = parse(&bytes)?;
+        match array.descr.as_str() {
+            "<i4" | "|i4" => array.values(4, |b| i32::from_le_bytes(b.try_into().unwrap())),
+            "<i8" => array
+                .values(8, |b| i64::from_le_bytes(b.try_into().unwrap()))?
+                .into_iter()
+                .map(|x| i32::try_from(x).map_err(|_| format!("{} overflows int32", x)))
+                .collect(),
+            descr => Err(format!("expected int32 or int64 data, found {}", descr)),
+        }
+    };
+    decode().map_err(|msg| CintError::Npy {
+        path: path.to_string(),
+        msg,
+    })
+}
+
+/// Float array such as `mol._env`, flattened in C order.
+pub fn read_f64(path: &str) -> Result<Vec<f64>, CintError> {
+    let bytes = read(path)?;
+    let decode = || -> Result<Vec<f64>, String> {
+        let array = parse(&bytes)?;
+        match array.descr.as_str() {
+            "<f8" => array.values(8, |b| f64::from_le_bytes(b.try_into().unwrap())),
+            descr => Err(format!("expected float64 data, found {}", descr)),
+        }
+    };
+    decode().map_err(|msg| CintError::Npy {
+        path: path.to_string(),
+        msg,
+    })
+}
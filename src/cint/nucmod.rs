@@ -0,0 +1,35 @@
+//! Finite-nucleus models. Gaussian nuclear exponents follow L. Visscher and K. G. Dyall,
+//! At. Data Nucl. Data Tables 67, 207 (1997), as `dyall_nuc_mod` in PySCF.
+
+/// Mass number of the isotope used for the nuclear radius, indexed by nuclear charge
+/// (0 for ghost atoms and elements without a tabulated isotope).
+#[rustfmt::skip]
+pub static ISOTOPE_MASS_NUMBER: [u16; 119] = [
+    0,
+    1, 4,
+    7, 9, 11, 12, 14, 16, 19, 20,
+    23, 24, 27, 28, 31, 32, 35, 40,
+    39, 40, 45, 48, 51, 52, 55, 56, 59, 58, 63, 64, 69, 74, 75, 80, 79, 84,
+    85, 88, 89, 90, 93, 98, 98, 102, 103, 106, 107, 114, 115, 120, 121, 130, 127, 132,
+    133, 138, 139, 140, 141, 144, 145, 152, 153, 158, 159, 164, 165, 166, 169, 174, 175,
+    180, 181, 184, 187, 192, 193, 195, 197, 202, 205, 208, 209, 209, 210, 222,
+    223, 226, 227, 232, 231, 238, 237, 244, 243, 247, 247, 251, 252, 257, 258, 259, 262,
+    261, 262, 263, 262, 265, 266, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Bohr radius in fm.
+const BOHR_FM: f64 = 52917.7249;
+
+/// RMS nuclear radius in bohr, `r = 0.836 A^(1/3) + 0.570` fm.
+pub fn nuclear_radius(charge: u8) -> f64 {
+    let mass = ISOTOPE_MASS_NUMBER
+        .get(charge as usize)
+        .copied()
+        .unwrap_or(0) as f64;
+    (0.836 * mass.cbrt() + 0.570) / BOHR_FM
+}
+
+/// Exponent `zeta = 3 / (2 r^2)` of the Gaussian charge distribution of nucleus `charge`.
+pub fn gaussian_nuc_zeta(charge: u8) -> f64 {
+    1.5 / nuclear_radius(charge).powi(2)
+}
@@ -0,0 +1,101 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use super::{
+    error::CintError,
+    intor::Intor,
+    kind::IntegralKind,
+    libcint::{CINTOpt, CINTOptimizerFunction, CINTdel_optimizer},
+};
+
+/// Owned `CINTOpt`, released with `CINTdel_optimizer` on drop.
+#[derive(Debug)]
+pub struct Optimizer {
+    opt: *mut CINTOpt,
+    opt_func: CINTOptimizerFunction,
+}
+
+// libcint only reads the optimizer while evaluating integrals.
+unsafe impl Send for Optimizer {}
+unsafe impl Sync for Optimizer {}
+
+impl Optimizer {
+    /// Optimizer of `kind` for the atoms, shells and env of `intor`.
+    pub fn new(intor: &Intor, kind: &IntegralKind) -> Result<Self, CintError> {
+        let mut opt: *mut CINTOpt = std::ptr::null_mut();
+        match kind.optimizer {
+            Some(func) => unsafe {
+                func(
+                    &mut opt as *mut *mut CINTOpt as *mut *const CINTOpt,
+                    intor.atm_ptr(),
+                    intor.natm(),
+                    intor.bas_ptr(),
+                    intor.nbas(),
+                    intor.env_ptr(),
+                )
+            },
+            None => return Err(CintError::NullFunction),
+        }
+        Ok(Self {
+            opt,
+            opt_func: kind.optimizer,
+        })
+    }
+
+    pub fn as_ptr(&self) -> *const CINTOpt {
+        self.opt
+    }
+
+    /// Whether this optimizer was built by `opt_func`.
+    pub fn is_for(&self, opt_func: CINTOptimizerFunction) -> bool {
+        self.opt_func.map(|f| f as usize) == opt_func.map(|f| f as usize)
+    }
+}
+
+impl Drop for Optimizer {
+    fn drop(&mut self) {
+        if !self.opt.is_null() {
+            unsafe { CINTdel_optimizer(&mut self.opt) };
+        }
+    }
+}
+
+/// Optimizers of one `Intor`, built on first use and keyed by their optimizer function.
+#[derive(Debug, Default)]
+pub(super) struct OptimizerCache(Mutex<HashMap<usize, Arc<Optimizer>>>);
+
+impl Clone for OptimizerCache {
+    fn clone(&self) -> Self {
+        let cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        Self(Mutex::new(cache.clone()))
+    }
+}
+
+impl OptimizerCache {
+    /// Optimizer of `kind` for `intor`, `None` when `kind` has no optimizer function.
+    pub(super) fn get(
+        &self,
+        intor: &Intor,
+        kind: &IntegralKind,
+    ) -> Result<Option<Arc<Optimizer>>, CintError> {
+        let Some(func) = kind.optimizer else {
+            return Ok(None);
+        };
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(opt) = cache.get(&(func as usize)) {
+            return Ok(Some(opt.clone()));
+        }
+        let opt = Arc::new(Optimizer::new(intor, kind)?);
+        cache.insert(func as usize, opt.clone());
+        Ok(Some(opt))
+    }
+
+    pub(super) fn clear(&self) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
@@ -1,39 +1,60 @@
 use core::{io::{bse::{ElectronShells, Elements}, PERIODIC_TABLE}, num::convert::aa2au};
-use std::collections::BTreeMap;
-
-use crate::cint::NAtom;
-
-use super::{cdata::CintBasis, libcint::CINTgto_norm};
+use serde::{Deserialize, Serialize};
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use crate::cint::{nucmod::gaussian_nuc_zeta, NAtom, NUC_FRAC_CHARGE, NUC_GAUSS};
+
+use super::{
+    basis_format::BasisMap, basis_spec::BasisSpec, cdata::CintBasis, error::CintError,
+    libcint::CINTgto_norm,
+};
+
+/// How coefficients read from a basis set are scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Keep the coefficients as written.
+    None,
+    /// Multiply by the primitive norm `CINTgto_norm` (`CGTO::norm`).
+    Primitive,
+    /// Also scale every contraction to unit norm, like PySCF's `make_bas_env`.
+    Contracted,
+}
 
 pub trait GetCGTO {
     type OutPut;
-    fn to_cgto(&self, kappa_of: i8, norm: bool) -> Self::OutPut;
+    fn to_cgto(&self, kappa_of: i8, norm: Normalization) -> Self::OutPut;
+}
+
+fn parse_value(value: &str) -> Result<f64, CintError> {
+    value.parse().map_err(|_| CintError::BasisValue {
+        value: value.to_string(),
+    })
 }
 
 impl GetCGTO for ElectronShells {
-    type OutPut = CGTO;
-    fn to_cgto(&self, kappa_of: i8, norm: bool) -> Self::OutPut {
+    type OutPut = Result<CGTO, CintError>;
+    fn to_cgto(&self, kappa_of: i8, norm: Normalization) -> Self::OutPut {
         let cgto = CGTO {
             kappa_of,
             angl: self.angular_momentum().clone(),
-            exp: self.exponents().iter().map(|x| x.parse().unwrap()).collect(),
+            exp: self
+                .exponents()
+                .iter()
+                .map(|x| parse_value(x))
+                .collect::<Result<_, _>>()?,
             coeff: self
                 .coefficients()
                 .iter()
-                .map(|y| y.iter().map(|x| x.parse().unwrap()).collect())
-                .collect(),
+                .map(|y| y.iter().map(|x| parse_value(x)).collect())
+                .collect::<Result<_, _>>()?,
         };
-        if norm {
-            cgto.norm()
-        } else {
-            cgto
-        }
+        Ok(cgto.with_norm(norm))
     }
 }
 
 impl GetCGTO for Elements {
-    type OutPut = Vec<CGTO>;
-    fn to_cgto(&self, kappa_of: i8, norm: bool) -> Self::OutPut {
+    type OutPut = Result<Vec<CGTO>, CintError>;
+    fn to_cgto(&self, kappa_of: i8, norm: Normalization) -> Self::OutPut {
         self.electron_shells()
             .iter()
             .map(|eshl| eshl.to_cgto(kappa_of, norm))
@@ -41,7 +62,15 @@ impl GetCGTO for Elements {
     }
 }
 
-#[derive(Debug, Clone)]
+/// `int_0^inf r^n exp(-alpha r^2) dr` for even `n`, the overlap of two radial primitives.
+fn gaussian_int(n: i32, alpha: f64) -> f64 {
+    let m = (n + 1) as f64 / 2.0;
+    // Gamma(m) for half-integer m
+    let gamma = (0..n / 2).fold(std::f64::consts::PI.sqrt(), |g, k| g * (k as f64 + 0.5));
+    gamma / (2.0 * alpha.powf(m))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CGTO {
     pub kappa_of: i8,
     pub angl: Vec<u8>,
@@ -50,29 +79,109 @@ pub struct CGTO {
 }
 
 impl CGTO {
+    /// Whether the coefficient rows are contractions of the single angular momentum
+    /// (general contraction) rather than one row per entry of `angl` (e.g. SP shells).
+    pub fn is_general(&self) -> bool {
+        self.angl.len() == 1
+    }
+
+    /// Number of contractions of each shell, every row of a general contraction or the one
+    /// row of each entry of `angl`.
+    pub fn nctr(&self) -> usize {
+        match self.is_general() {
+            true => self.coeff.len(),
+            false => 1,
+        }
+    }
+
+    /// Angular momentum of coefficient row `ictr`.
+    pub fn angl_of_coeff(&self, ictr: usize) -> u8 {
+        match self.is_general() {
+            true => self.angl[0],
+            false => self.angl[ictr],
+        }
+    }
+
+    /// Rows of `coeff` match `angl` and every row has one coefficient per exponent.
+    pub fn is_consistent(&self) -> bool {
+        !self.angl.is_empty()
+            && (self.is_general() || self.coeff.len() == self.angl.len())
+            && self.coeff.iter().all(|row| row.len() == self.exp.len())
+    }
+
     pub fn norm(mut self) -> Self {
         // pub fn CINTgto_norm(n: ::std::os::raw::c_int, a: f64) -> f64;
         self.coeff = self
             .coeff
             .iter()
             .enumerate()
-            .map(|(i, coeff)| {
+            .map(|(ictr, coeff)| {
+                let angl = self.angl_of_coeff(ictr);
                 coeff
                     .iter()
                     .enumerate()
-                    .map(|(ic, c)| c * unsafe { CINTgto_norm(self.angl[i].into(), self.exp[ic]) })
+                    .map(|(ic, c)| c * unsafe { CINTgto_norm(angl.into(), self.exp[ic]) })
                     .collect()
             })
             .collect();
         self
     }
 
+    /// Inverse of `norm`, the coefficients as basis set files list them.
+    pub fn denorm(mut self) -> Self {
+        self.coeff = self
+            .coeff
+            .iter()
+            .enumerate()
+            .map(|(ictr, coeff)| {
+                let angl = self.angl_of_coeff(ictr);
+                coeff
+                    .iter()
+                    .enumerate()
+                    .map(|(ic, c)| c / unsafe { CINTgto_norm(angl.into(), self.exp[ic]) })
+                    .collect()
+            })
+            .collect();
+        self
+    }
+
+    /// Coefficients scaled by `norm`.
+    pub fn with_norm(self, norm: Normalization) -> Self {
+        match norm {
+            Normalization::None => self,
+            Normalization::Primitive => self.norm(),
+            Normalization::Contracted => self.norm_contracted(),
+        }
+    }
+
+    /// `norm`, then scale every contraction so that its radial part has unit norm.
+    pub fn norm_contracted(self) -> Self {
+        let mut cgto = self.norm();
+        let angl: Vec<u8> = (0..cgto.coeff.len())
+            .map(|ictr| cgto.angl_of_coeff(ictr))
+            .collect();
+        let exp = &cgto.exp;
+        for (coeff, angl) in cgto.coeff.iter_mut().zip(angl) {
+            let n = 2 * angl as i32 + 2;
+            let mut s = 0.0;
+            for (ip, cp) in coeff.iter().enumerate() {
+                for (iq, cq) in coeff.iter().enumerate() {
+                    s += cp * cq * gaussian_int(n, exp[ip] + exp[iq]);
+                }
+            }
+            coeff.iter_mut().for_each(|c| *c /= s.sqrt());
+        }
+        cgto
+    }
+
+    /// Shell of `angl[iangl]`, its `nctr` coefficient rows start at `ptr_coeff` and are
+    /// stored one after another (primitive index fastest), as libcint expects.
     pub fn gen_bas(&self, iangl: usize, ptr_exp: i32, ptr_coeff: i32) -> CintBasis {
         CintBasis {
             atom_of: -1,
             ang_of: self.angl[iangl].into(),
             nprim_of: self.exp.len() as i32,
-            nctr_of: 1,
+            nctr_of: self.nctr() as i32,
             kappa_of: self.kappa_of as i32,
             ptr_exp,
             ptr_coeff,
@@ -81,7 +190,7 @@ impl CGTO {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CintAtomGroup {
     basis: Option<Vec<CGTO>>,
     charge_of: u8,
@@ -89,6 +198,7 @@ pub struct CintAtomGroup {
     zeta: f64,
     frac_charge: f64,
     coordinates: Vec<[f64; 3]>,
+    ghost: bool,
 }
 
 impl CintAtomGroup {
@@ -107,8 +217,14 @@ impl CintAtomGroup {
             zeta,
             frac_charge,
             coordinates,
+            ghost: false,
         }
     }
+
+    pub fn ghost_mut(&mut self) -> &mut bool {
+        &mut self.ghost
+    }
+
     pub fn from_other_group(atom_group: &impl AtomGroup) -> Self {
         Self {
             basis: atom_group.basis().clone(),
@@ -117,71 +233,174 @@ impl CintAtomGroup {
             zeta: *atom_group.zeta(),
             frac_charge: *atom_group.frac_charge(),
             coordinates: atom_group.coordinates().clone(),
+            ghost: *atom_group.ghost(),
         }
     }
 
-    pub fn from_xyz(xyz_str: &str, basis: Option<BTreeMap<u8, Vec<CGTO>>>) -> Vec<Self> {
-        let mut natm: NAtom = 0;
-        let xyz_list: Vec<&str> = xyz_str.trim_end().lines().collect();
-        match xyz_list[0].parse() {
-            Err(why) => panic!("{:?}", why),
-            Ok(v) => natm = v,
+    /// Group the atoms of an xyz string by element, `basis` (if any) must cover them all.
+    /// Ghost atoms (`X-O`, `Gh(O)` or `@O`) form their own groups.
+    pub fn from_xyz(
+        xyz_str: &str,
+        basis: Option<BTreeMap<u8, Vec<CGTO>>>,
+    ) -> Result<Vec<Self>, CintError> {
+        let mut atoms_map: BTreeMap<(u8, bool), Vec<[f64; 3]>> = BTreeMap::new();
+        for atom in parse_xyz(xyz_str)? {
+            atoms_map
+                .entry((atom.charge, atom.ghost))
+                .or_default()
+                .push(atom.coord);
         }
 
-        let mut atoms_map: BTreeMap<u8, Vec<[f64; 3]>> = BTreeMap::new();
-
-        let mut counter = 0;
-        xyz_list[2..xyz_list.len()].iter().for_each(|line| {
-            let mut split_s = line.trim().split_whitespace();
-            let nuc = match split_s.next() {
-                Some(symbol) => match PERIODIC_TABLE.iter().position(|ele| *ele == symbol) {
-                    None => panic!(""),
-                    Some(nuc) => (nuc + 1) as u8,
-                },
-                None => panic!(""),
-            };
-
-            let mut coor = [0.0; 3];
-            for (i, x_str) in split_s.enumerate() {
-                match x_str.parse() {
-                    Ok(x) => coor[i] = aa2au(x),
-                    Err(why) => panic!(""),
-                }
-            }
-
-            match atoms_map.get_mut(&nuc) {
-                Some(atom_coors) => atom_coors.push(coor),
-                None => {
-                    atoms_map.insert(nuc, vec![coor]);
-                    ()
-                }
-            }
+        atoms_map
+            .into_iter()
+            .map(|((nuc, ghost), coors)| {
+                let basis = match &basis {
+                    Some(bas) => match bas.get(&nuc) {
+                        Some(b) => Some(b.to_vec()),
+                        None => return Err(CintError::MissingBasis { charge: nuc }),
+                    },
+                    None => None,
+                };
+                let mut atoms = Self::new(basis, nuc, 0, 0.0, 0.0, coors);
+                atoms.ghost = ghost;
+                Ok(atoms)
+            })
+            .collect()
+    }
 
-            counter += 1;
-        });
-        assert_eq!(natm, counter);
+    /// Like `from_xyz`, with the basis of every atom taken from `spec`. Atoms of one element
+    /// with different bases are put in different groups.
+    pub fn from_xyz_spec(
+        xyz_str: &str,
+        spec: &BasisSpec,
+        norm: Normalization,
+    ) -> Result<Vec<Self>, CintError> {
+        let mut atoms_map: BTreeMap<(u8, bool, usize), Vec<[f64; 3]>> = BTreeMap::new();
+        for (iatm, atom) in parse_xyz(xyz_str)?.into_iter().enumerate() {
+            let charge = atom.charge;
+            let isrc = spec
+                .source_of(iatm, atom.label, charge)
+                .ok_or(CintError::MissingBasis { charge })?;
+            atoms_map
+                .entry((atom.charge, atom.ghost, isrc))
+                .or_default()
+                .push(atom.coord);
+        }
 
+        let mut loaded: BTreeMap<usize, BasisMap> = BTreeMap::new();
         atoms_map
-            .iter()
-            .map(|(nuc, coors)| {
-                Self::new(
-                    match &basis {
-                        Some(bas) => match bas.get(&nuc) {
-                            Some(b) => Some(b.to_vec()),
-                            None => panic!(""),
-                        },
-                        None => None,
-                    },
-                    *nuc,
-                    0,
-                    0.0,
-                    0.0,
-                    coors.to_vec(),
-                )
+            .into_iter()
+            .map(|((nuc, ghost, isrc), coors)| {
+                let basis = match loaded.entry(isrc) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(spec.load(isrc, norm)?),
+                };
+                let basis = basis
+                    .get(&nuc)
+                    .cloned()
+                    .ok_or(CintError::MissingBasis { charge: nuc })?;
+                let mut atoms = Self::new(Some(basis), nuc, 0, 0.0, 0.0, coors);
+                atoms.ghost = ghost;
+                Ok(atoms)
             })
             .collect()
     }
+}
 
+/// Atom of an xyz line, coordinates in bohr.
+struct XyzAtom<'a> {
+    label: &'a str,
+    charge: u8,
+    ghost: bool,
+    coord: [f64; 3],
+}
+
+/// Atoms of an xyz string in input order, checked against the atom count of its header.
+fn parse_xyz(xyz_str: &str) -> Result<Vec<XyzAtom<'_>>, CintError> {
+    let xyz_list: Vec<&str> = xyz_str.trim_end().lines().collect();
+    let natm: NAtom = match xyz_list.first().map(|line| line.trim().parse()) {
+        Some(Ok(natm)) => natm,
+        Some(Err(why)) => {
+            return Err(CintError::Xyz {
+                line: 1,
+                msg: format!("bad atom count: {}", why),
+            })
+        }
+        None => {
+            return Err(CintError::Xyz {
+                line: 1,
+                msg: "empty input".to_string(),
+            })
+        }
+    };
+
+    let mut atoms = Vec::new();
+    for (i, line) in xyz_list.iter().enumerate().skip(2) {
+        let line_no = i + 1;
+        let mut split_s = line.split_whitespace();
+        let label = split_s.next().ok_or(CintError::Xyz {
+            line: line_no,
+            msg: "missing element symbol".to_string(),
+        })?;
+        let (symbol, label, ghost) = parse_label(label);
+        let charge = match PERIODIC_TABLE.iter().position(|ele| *ele == symbol) {
+            Some(nuc) => (nuc + 1) as u8,
+            None => {
+                return Err(CintError::UnknownElement {
+                    line: line_no,
+                    symbol: symbol.to_string(),
+                })
+            }
+        };
+
+        let coor = split_s
+            .map(|x_str| match x_str.parse() {
+                Ok(x) => Ok(aa2au(x)),
+                Err(_) => Err(CintError::Xyz {
+                    line: line_no,
+                    msg: format!("bad coordinate `{}`", x_str),
+                }),
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        let coord: [f64; 3] = coor.try_into().map_err(|coor: Vec<f64>| CintError::Xyz {
+            line: line_no,
+            msg: format!("expected 3 coordinates, found {}", coor.len()),
+        })?;
+
+        atoms.push(XyzAtom {
+            label,
+            charge,
+            ghost,
+            coord,
+        });
+    }
+
+    let counter = xyz_list.len().saturating_sub(2);
+    if natm != counter {
+        return Err(CintError::AtomCount {
+            expected: natm,
+            found: counter,
+        });
+    }
+    Ok(atoms)
+}
+
+/// Element symbol, label and ghost flag of an xyz atom label. The label loses its ghost
+/// marker, the symbol ends before a suffix such as `1`, `_a` or `:diffuse`.
+fn parse_label(label: &str) -> (&str, &str, bool) {
+    let ghost = label
+        .strip_prefix("X-")
+        .or_else(|| label.strip_prefix('@'))
+        .or_else(|| label.strip_prefix("Gh(").and_then(|s| s.strip_suffix(')')));
+    let (label, ghost) = match ghost {
+        Some(label) => (label, true),
+        None => (label, false),
+    };
+    let symbol = label
+        .split(|c: char| c.is_ascii_digit() || c == '_' || c == ':')
+        .next()
+        .unwrap_or(label);
+    (symbol, label, ghost)
 }
 
 impl AtomGroup for CintAtomGroup {
@@ -232,6 +451,10 @@ impl AtomGroup for CintAtomGroup {
     fn coordinates(&self) -> &Vec<[f64; 3]> {
         &self.coordinates
     }
+
+    fn ghost(&self) -> &bool {
+        &self.ghost
+    }
 }
 
 pub trait AtomGroup {
@@ -248,4 +471,20 @@ pub trait AtomGroup {
     fn zeta(&self) -> &f64;
     fn frac_charge(&self) -> &f64;
     fn coordinates(&self) -> &Vec<[f64; 3]>;
+    /// Ghost atoms keep the basis of element `charge_of` but have no nuclear charge. Groups
+    /// are real atoms unless they override this.
+    fn ghost(&self) -> &bool {
+        &false
+    }
+
+    /// Switch to a Gaussian nucleus with the Visscher–Dyall exponent of the element. Groups
+    /// with a fractional charge (`NUC_FRAC_CHARGE`) keep their model, libcint cannot smear
+    /// a fractional charge.
+    fn use_gaussian_nuc(&mut self) {
+        if *self.nuc_mod_of() == NUC_FRAC_CHARGE as u8 {
+            return;
+        }
+        *self.nuc_mod_of_mut() = NUC_GAUSS as u8;
+        *self.zeta_mut() = gaussian_nuc_zeta(*self.charge_of());
+    }
 }
@@ -0,0 +1,1047 @@
+//! Metadata of every integral family bound in `libcint.rs`.
+//!
+//! Component counts follow `_INTOR_FUNCTIONS` in PySCF's `gto/moleintor.py`. Hermiticity is
+//! given for the cartesian/spherical matrices and left as `NonHermitian` whenever unsure.
+//! The `int1e_grids*` family is not listed: its shell tuple carries a grid range and its output
+//! an extra grid dimension.
+
+use std::os::raw::c_int;
+
+use super::{
+    kind::{Hermiticity::*, IntegralKind},
+    libcint::*,
+};
+
+// `cint_funcs.h` declares the plain 2c2e and 3c2e integrals through the function typedefs,
+// which bindgen does not bind.
+macro_rules! typedef_declared {
+    ($($intor:ident),*; $($optimizer:ident),*) => {
+        extern "C" {
+            $(
+                fn $intor(
+                    out: *mut f64,
+                    dims: *const c_int,
+                    shls: *const c_int,
+                    atm: *const c_int,
+                    natm: c_int,
+                    bas: *const c_int,
+                    nbas: c_int,
+                    env: *const f64,
+                    opt: *const CINTOpt,
+                    cache: *mut f64,
+                ) -> c_int;
+            )*
+            $(
+                fn $optimizer(
+                    opt: *mut *const CINTOpt,
+                    atm: *const c_int,
+                    natm: c_int,
+                    bas: *const c_int,
+                    nbas: c_int,
+                    env: *const f64,
+                );
+            )*
+        }
+    };
+}
+
+typedef_declared! {
+    int2c2e_cart, int2c2e_sph, int2c2e_spinor, int3c2e_cart, int3c2e_sph, int3c2e_spinor;
+    int2c2e_optimizer, int3c2e_optimizer
+}
+
+macro_rules! integral_kind {
+    (
+        $kind:ident, $name:literal, $ncenter:literal, $ncomp:literal, $ncomp_spinor:literal,
+        $hermi:ident, $cart:ident, $sph:ident, $spinor:ident, $optimizer:ident
+    ) => {
+        pub const $kind: IntegralKind = IntegralKind {
+            name: $name,
+            ncenter: $ncenter,
+            ncomp: $ncomp,
+            ncomp_spinor: $ncomp_spinor,
+            hermi: $hermi,
+            cart: Some($cart),
+            sph: Some($sph),
+            spinor: Some($spinor),
+            optimizer: Some($optimizer),
+        };
+    };
+}
+
+integral_kind! {
+    INT2E, "int2e", 4, 1, 1, Hermitian,
+    int2e_cart, int2e_sph, int2e_spinor, int2e_optimizer
+}
+integral_kind! {
+    INT1E_OVLP, "int1e_ovlp", 2, 1, 1, Hermitian,
+    int1e_ovlp_cart, int1e_ovlp_sph, int1e_ovlp_spinor, int1e_ovlp_optimizer
+}
+integral_kind! {
+    INT1E_NUC, "int1e_nuc", 2, 1, 1, Hermitian,
+    int1e_nuc_cart, int1e_nuc_sph, int1e_nuc_spinor, int1e_nuc_optimizer
+}
+integral_kind! {
+    INT1E_KIN, "int1e_kin", 2, 1, 1, Hermitian,
+    int1e_kin_cart, int1e_kin_sph, int1e_kin_spinor, int1e_kin_optimizer
+}
+integral_kind! {
+    INT1E_IA01P, "int1e_ia01p", 2, 3, 3, NonHermitian,
+    int1e_ia01p_cart, int1e_ia01p_sph, int1e_ia01p_spinor, int1e_ia01p_optimizer
+}
+integral_kind! {
+    INT1E_GIAO_IRJXP, "int1e_giao_irjxp", 2, 3, 3, AntiHermitian,
+    int1e_giao_irjxp_cart, int1e_giao_irjxp_sph, int1e_giao_irjxp_spinor, int1e_giao_irjxp_optimizer
+}
+integral_kind! {
+    INT1E_CG_IRXP, "int1e_cg_irxp", 2, 3, 3, AntiHermitian,
+    int1e_cg_irxp_cart, int1e_cg_irxp_sph, int1e_cg_irxp_spinor, int1e_cg_irxp_optimizer
+}
+integral_kind! {
+    INT1E_GIAO_A11PART, "int1e_giao_a11part", 2, 9, 9, NonHermitian,
+    int1e_giao_a11part_cart, int1e_giao_a11part_sph,
+    int1e_giao_a11part_spinor, int1e_giao_a11part_optimizer
+}
+integral_kind! {
+    INT1E_CG_A11PART, "int1e_cg_a11part", 2, 9, 9, NonHermitian,
+    int1e_cg_a11part_cart, int1e_cg_a11part_sph, int1e_cg_a11part_spinor, int1e_cg_a11part_optimizer
+}
+integral_kind! {
+    INT1E_A01GP, "int1e_a01gp", 2, 9, 9, NonHermitian,
+    int1e_a01gp_cart, int1e_a01gp_sph, int1e_a01gp_spinor, int1e_a01gp_optimizer
+}
+integral_kind! {
+    INT1E_IGKIN, "int1e_igkin", 2, 3, 3, NonHermitian,
+    int1e_igkin_cart, int1e_igkin_sph, int1e_igkin_spinor, int1e_igkin_optimizer
+}
+integral_kind! {
+    INT1E_IGOVLP, "int1e_igovlp", 2, 3, 3, NonHermitian,
+    int1e_igovlp_cart, int1e_igovlp_sph, int1e_igovlp_spinor, int1e_igovlp_optimizer
+}
+integral_kind! {
+    INT1E_IGNUC, "int1e_ignuc", 2, 3, 3, NonHermitian,
+    int1e_ignuc_cart, int1e_ignuc_sph, int1e_ignuc_spinor, int1e_ignuc_optimizer
+}
+integral_kind! {
+    INT1E_PNUCP, "int1e_pnucp", 2, 1, 1, Hermitian,
+    int1e_pnucp_cart, int1e_pnucp_sph, int1e_pnucp_spinor, int1e_pnucp_optimizer
+}
+integral_kind! {
+    INT1E_Z, "int1e_z", 2, 1, 1, Hermitian,
+    int1e_z_cart, int1e_z_sph, int1e_z_spinor, int1e_z_optimizer
+}
+integral_kind! {
+    INT1E_ZZ, "int1e_zz", 2, 1, 1, Hermitian,
+    int1e_zz_cart, int1e_zz_sph, int1e_zz_spinor, int1e_zz_optimizer
+}
+integral_kind! {
+    INT1E_R, "int1e_r", 2, 3, 3, Hermitian,
+    int1e_r_cart, int1e_r_sph, int1e_r_spinor, int1e_r_optimizer
+}
+integral_kind! {
+    INT1E_R2, "int1e_r2", 2, 1, 1, Hermitian,
+    int1e_r2_cart, int1e_r2_sph, int1e_r2_spinor, int1e_r2_optimizer
+}
+integral_kind! {
+    INT1E_R4, "int1e_r4", 2, 1, 1, Hermitian,
+    int1e_r4_cart, int1e_r4_sph, int1e_r4_spinor, int1e_r4_optimizer
+}
+integral_kind! {
+    INT1E_RR, "int1e_rr", 2, 9, 9, Hermitian,
+    int1e_rr_cart, int1e_rr_sph, int1e_rr_spinor, int1e_rr_optimizer
+}
+integral_kind! {
+    INT1E_RRR, "int1e_rrr", 2, 27, 27, Hermitian,
+    int1e_rrr_cart, int1e_rrr_sph, int1e_rrr_spinor, int1e_rrr_optimizer
+}
+integral_kind! {
+    INT1E_RRRR, "int1e_rrrr", 2, 81, 81, Hermitian,
+    int1e_rrrr_cart, int1e_rrrr_sph, int1e_rrrr_spinor, int1e_rrrr_optimizer
+}
+integral_kind! {
+    INT1E_Z_ORIGJ, "int1e_z_origj", 2, 1, 1, NonHermitian,
+    int1e_z_origj_cart, int1e_z_origj_sph, int1e_z_origj_spinor, int1e_z_origj_optimizer
+}
+integral_kind! {
+    INT1E_ZZ_ORIGJ, "int1e_zz_origj", 2, 1, 1, NonHermitian,
+    int1e_zz_origj_cart, int1e_zz_origj_sph, int1e_zz_origj_spinor, int1e_zz_origj_optimizer
+}
+integral_kind! {
+    INT1E_R_ORIGJ, "int1e_r_origj", 2, 3, 3, NonHermitian,
+    int1e_r_origj_cart, int1e_r_origj_sph, int1e_r_origj_spinor, int1e_r_origj_optimizer
+}
+integral_kind! {
+    INT1E_RR_ORIGJ, "int1e_rr_origj", 2, 9, 9, NonHermitian,
+    int1e_rr_origj_cart, int1e_rr_origj_sph, int1e_rr_origj_spinor, int1e_rr_origj_optimizer
+}
+integral_kind! {
+    INT1E_R2_ORIGJ, "int1e_r2_origj", 2, 1, 1, NonHermitian,
+    int1e_r2_origj_cart, int1e_r2_origj_sph, int1e_r2_origj_spinor, int1e_r2_origj_optimizer
+}
+integral_kind! {
+    INT1E_R4_ORIGJ, "int1e_r4_origj", 2, 1, 1, NonHermitian,
+    int1e_r4_origj_cart, int1e_r4_origj_sph, int1e_r4_origj_spinor, int1e_r4_origj_optimizer
+}
+integral_kind! {
+    INT1E_P4, "int1e_p4", 2, 1, 1, Hermitian,
+    int1e_p4_cart, int1e_p4_sph, int1e_p4_spinor, int1e_p4_optimizer
+}
+integral_kind! {
+    INT1E_PRINVP, "int1e_prinvp", 2, 1, 1, Hermitian,
+    int1e_prinvp_cart, int1e_prinvp_sph, int1e_prinvp_spinor, int1e_prinvp_optimizer
+}
+integral_kind! {
+    INT1E_PRINVXP, "int1e_prinvxp", 2, 3, 3, AntiHermitian,
+    int1e_prinvxp_cart, int1e_prinvxp_sph, int1e_prinvxp_spinor, int1e_prinvxp_optimizer
+}
+integral_kind! {
+    INT1E_PNUCXP, "int1e_pnucxp", 2, 3, 3, AntiHermitian,
+    int1e_pnucxp_cart, int1e_pnucxp_sph, int1e_pnucxp_spinor, int1e_pnucxp_optimizer
+}
+integral_kind! {
+    INT1E_IRP, "int1e_irp", 2, 9, 9, NonHermitian,
+    int1e_irp_cart, int1e_irp_sph, int1e_irp_spinor, int1e_irp_optimizer
+}
+integral_kind! {
+    INT1E_IRRP, "int1e_irrp", 2, 27, 27, NonHermitian,
+    int1e_irrp_cart, int1e_irrp_sph, int1e_irrp_spinor, int1e_irrp_optimizer
+}
+integral_kind! {
+    INT1E_IRPR, "int1e_irpr", 2, 27, 27, NonHermitian,
+    int1e_irpr_cart, int1e_irpr_sph, int1e_irpr_spinor, int1e_irpr_optimizer
+}
+integral_kind! {
+    INT1E_GGOVLP, "int1e_ggovlp", 2, 9, 9, NonHermitian,
+    int1e_ggovlp_cart, int1e_ggovlp_sph, int1e_ggovlp_spinor, int1e_ggovlp_optimizer
+}
+integral_kind! {
+    INT1E_GGKIN, "int1e_ggkin", 2, 9, 9, NonHermitian,
+    int1e_ggkin_cart, int1e_ggkin_sph, int1e_ggkin_spinor, int1e_ggkin_optimizer
+}
+integral_kind! {
+    INT1E_GGNUC, "int1e_ggnuc", 2, 9, 9, NonHermitian,
+    int1e_ggnuc_cart, int1e_ggnuc_sph, int1e_ggnuc_spinor, int1e_ggnuc_optimizer
+}
+integral_kind! {
+    INT1E_GRJXP, "int1e_grjxp", 2, 9, 9, NonHermitian,
+    int1e_grjxp_cart, int1e_grjxp_sph, int1e_grjxp_spinor, int1e_grjxp_optimizer
+}
+integral_kind! {
+    INT1E_RINV, "int1e_rinv", 2, 1, 1, Hermitian,
+    int1e_rinv_cart, int1e_rinv_sph, int1e_rinv_spinor, int1e_rinv_optimizer
+}
+integral_kind! {
+    INT1E_DRINV, "int1e_drinv", 2, 3, 3, NonHermitian,
+    int1e_drinv_cart, int1e_drinv_sph, int1e_drinv_spinor, int1e_drinv_optimizer
+}
+integral_kind! {
+    INT2E_IG1, "int2e_ig1", 4, 3, 3, NonHermitian,
+    int2e_ig1_cart, int2e_ig1_sph, int2e_ig1_spinor, int2e_ig1_optimizer
+}
+integral_kind! {
+    INT2E_GG1, "int2e_gg1", 4, 9, 9, NonHermitian,
+    int2e_gg1_cart, int2e_gg1_sph, int2e_gg1_spinor, int2e_gg1_optimizer
+}
+integral_kind! {
+    INT2E_G1G2, "int2e_g1g2", 4, 9, 9, NonHermitian,
+    int2e_g1g2_cart, int2e_g1g2_sph, int2e_g1g2_spinor, int2e_g1g2_optimizer
+}
+integral_kind! {
+    INT2E_P1VXP1, "int2e_p1vxp1", 4, 3, 3, AntiHermitian,
+    int2e_p1vxp1_cart, int2e_p1vxp1_sph, int2e_p1vxp1_spinor, int2e_p1vxp1_optimizer
+}
+integral_kind! {
+    INT2E_IP1V_RC1, "int2e_ip1v_rc1", 4, 9, 9, NonHermitian,
+    int2e_ip1v_rc1_cart, int2e_ip1v_rc1_sph, int2e_ip1v_rc1_spinor, int2e_ip1v_rc1_optimizer
+}
+integral_kind! {
+    INT2E_IP1V_R1, "int2e_ip1v_r1", 4, 9, 9, NonHermitian,
+    int2e_ip1v_r1_cart, int2e_ip1v_r1_sph, int2e_ip1v_r1_spinor, int2e_ip1v_r1_optimizer
+}
+integral_kind! {
+    INT2E_IPVG1_XP1, "int2e_ipvg1_xp1", 4, 9, 9, NonHermitian,
+    int2e_ipvg1_xp1_cart, int2e_ipvg1_xp1_sph, int2e_ipvg1_xp1_spinor, int2e_ipvg1_xp1_optimizer
+}
+integral_kind! {
+    INT2E_IPVG2_XP1, "int2e_ipvg2_xp1", 4, 9, 9, NonHermitian,
+    int2e_ipvg2_xp1_cart, int2e_ipvg2_xp1_sph, int2e_ipvg2_xp1_spinor, int2e_ipvg2_xp1_optimizer
+}
+integral_kind! {
+    INT1E_INUC_RCXP, "int1e_inuc_rcxp", 2, 3, 3, NonHermitian,
+    int1e_inuc_rcxp_cart, int1e_inuc_rcxp_sph, int1e_inuc_rcxp_spinor, int1e_inuc_rcxp_optimizer
+}
+integral_kind! {
+    INT1E_INUC_RXP, "int1e_inuc_rxp", 2, 3, 3, NonHermitian,
+    int1e_inuc_rxp_cart, int1e_inuc_rxp_sph, int1e_inuc_rxp_spinor, int1e_inuc_rxp_optimizer
+}
+integral_kind! {
+    INT1E_SIGMA, "int1e_sigma", 2, 12, 3, NonHermitian,
+    int1e_sigma_cart, int1e_sigma_sph, int1e_sigma_spinor, int1e_sigma_optimizer
+}
+integral_kind! {
+    INT1E_SPSIGMASP, "int1e_spsigmasp", 2, 12, 3, NonHermitian,
+    int1e_spsigmasp_cart, int1e_spsigmasp_sph, int1e_spsigmasp_spinor, int1e_spsigmasp_optimizer
+}
+integral_kind! {
+    INT1E_SRSR, "int1e_srsr", 2, 4, 1, NonHermitian,
+    int1e_srsr_cart, int1e_srsr_sph, int1e_srsr_spinor, int1e_srsr_optimizer
+}
+integral_kind! {
+    INT1E_SR, "int1e_sr", 2, 4, 1, NonHermitian,
+    int1e_sr_cart, int1e_sr_sph, int1e_sr_spinor, int1e_sr_optimizer
+}
+integral_kind! {
+    INT1E_SRSP, "int1e_srsp", 2, 4, 1, NonHermitian,
+    int1e_srsp_cart, int1e_srsp_sph, int1e_srsp_spinor, int1e_srsp_optimizer
+}
+integral_kind! {
+    INT1E_SPSP, "int1e_spsp", 2, 4, 1, NonHermitian,
+    int1e_spsp_cart, int1e_spsp_sph, int1e_spsp_spinor, int1e_spsp_optimizer
+}
+integral_kind! {
+    INT1E_SP, "int1e_sp", 2, 4, 1, NonHermitian,
+    int1e_sp_cart, int1e_sp_sph, int1e_sp_spinor, int1e_sp_optimizer
+}
+integral_kind! {
+    INT1E_SPNUCSP, "int1e_spnucsp", 2, 4, 1, NonHermitian,
+    int1e_spnucsp_cart, int1e_spnucsp_sph, int1e_spnucsp_spinor, int1e_spnucsp_optimizer
+}
+integral_kind! {
+    INT1E_SPRINVSP, "int1e_sprinvsp", 2, 4, 1, NonHermitian,
+    int1e_sprinvsp_cart, int1e_sprinvsp_sph, int1e_sprinvsp_spinor, int1e_sprinvsp_optimizer
+}
+integral_kind! {
+    INT1E_SRNUCSR, "int1e_srnucsr", 2, 4, 1, NonHermitian,
+    int1e_srnucsr_cart, int1e_srnucsr_sph, int1e_srnucsr_spinor, int1e_srnucsr_optimizer
+}
+integral_kind! {
+    INT1E_SPRSP, "int1e_sprsp", 2, 12, 3, NonHermitian,
+    int1e_sprsp_cart, int1e_sprsp_sph, int1e_sprsp_spinor, int1e_sprsp_optimizer
+}
+integral_kind! {
+    INT1E_GOVLP, "int1e_govlp", 2, 3, 3, NonHermitian,
+    int1e_govlp_cart, int1e_govlp_sph, int1e_govlp_spinor, int1e_govlp_optimizer
+}
+integral_kind! {
+    INT1E_GNUC, "int1e_gnuc", 2, 3, 3, NonHermitian,
+    int1e_gnuc_cart, int1e_gnuc_sph, int1e_gnuc_spinor, int1e_gnuc_optimizer
+}
+integral_kind! {
+    INT1E_CG_SA10SA01, "int1e_cg_sa10sa01", 2, 36, 9, NonHermitian,
+    int1e_cg_sa10sa01_cart, int1e_cg_sa10sa01_sph,
+    int1e_cg_sa10sa01_spinor, int1e_cg_sa10sa01_optimizer
+}
+integral_kind! {
+    INT1E_CG_SA10SP, "int1e_cg_sa10sp", 2, 12, 3, NonHermitian,
+    int1e_cg_sa10sp_cart, int1e_cg_sa10sp_sph, int1e_cg_sa10sp_spinor, int1e_cg_sa10sp_optimizer
+}
+integral_kind! {
+    INT1E_CG_SA10NUCSP, "int1e_cg_sa10nucsp", 2, 12, 3, NonHermitian,
+    int1e_cg_sa10nucsp_cart, int1e_cg_sa10nucsp_sph,
+    int1e_cg_sa10nucsp_spinor, int1e_cg_sa10nucsp_optimizer
+}
+integral_kind! {
+    INT1E_GIAO_SA10SA01, "int1e_giao_sa10sa01", 2, 36, 9, NonHermitian,
+    int1e_giao_sa10sa01_cart, int1e_giao_sa10sa01_sph,
+    int1e_giao_sa10sa01_spinor, int1e_giao_sa10sa01_optimizer
+}
+integral_kind! {
+    INT1E_GIAO_SA10SP, "int1e_giao_sa10sp", 2, 12, 3, NonHermitian,
+    int1e_giao_sa10sp_cart, int1e_giao_sa10sp_sph,
+    int1e_giao_sa10sp_spinor, int1e_giao_sa10sp_optimizer
+}
+integral_kind! {
+    INT1E_GIAO_SA10NUCSP, "int1e_giao_sa10nucsp", 2, 12, 3, NonHermitian,
+    int1e_giao_sa10nucsp_cart, int1e_giao_sa10nucsp_sph,
+    int1e_giao_sa10nucsp_spinor, int1e_giao_sa10nucsp_optimizer
+}
+integral_kind! {
+    INT1E_SA01SP, "int1e_sa01sp", 2, 12, 3, NonHermitian,
+    int1e_sa01sp_cart, int1e_sa01sp_sph, int1e_sa01sp_spinor, int1e_sa01sp_optimizer
+}
+integral_kind! {
+    INT1E_SPGSP, "int1e_spgsp", 2, 12, 3, NonHermitian,
+    int1e_spgsp_cart, int1e_spgsp_sph, int1e_spgsp_spinor, int1e_spgsp_optimizer
+}
+integral_kind! {
+    INT1E_SPGNUCSP, "int1e_spgnucsp", 2, 12, 3, NonHermitian,
+    int1e_spgnucsp_cart, int1e_spgnucsp_sph, int1e_spgnucsp_spinor, int1e_spgnucsp_optimizer
+}
+integral_kind! {
+    INT1E_SPGSA01, "int1e_spgsa01", 2, 36, 9, NonHermitian,
+    int1e_spgsa01_cart, int1e_spgsa01_sph, int1e_spgsa01_spinor, int1e_spgsa01_optimizer
+}
+integral_kind! {
+    INT2E_SPSP1, "int2e_spsp1", 4, 4, 1, NonHermitian,
+    int2e_spsp1_cart, int2e_spsp1_sph, int2e_spsp1_spinor, int2e_spsp1_optimizer
+}
+integral_kind! {
+    INT2E_SPSP1SPSP2, "int2e_spsp1spsp2", 4, 16, 1, NonHermitian,
+    int2e_spsp1spsp2_cart, int2e_spsp1spsp2_sph, int2e_spsp1spsp2_spinor, int2e_spsp1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_SRSR1, "int2e_srsr1", 4, 4, 1, NonHermitian,
+    int2e_srsr1_cart, int2e_srsr1_sph, int2e_srsr1_spinor, int2e_srsr1_optimizer
+}
+integral_kind! {
+    INT2E_SRSR1SRSR2, "int2e_srsr1srsr2", 4, 16, 1, NonHermitian,
+    int2e_srsr1srsr2_cart, int2e_srsr1srsr2_sph, int2e_srsr1srsr2_spinor, int2e_srsr1srsr2_optimizer
+}
+integral_kind! {
+    INT2E_CG_SA10SP1, "int2e_cg_sa10sp1", 4, 12, 3, NonHermitian,
+    int2e_cg_sa10sp1_cart, int2e_cg_sa10sp1_sph, int2e_cg_sa10sp1_spinor, int2e_cg_sa10sp1_optimizer
+}
+integral_kind! {
+    INT2E_CG_SA10SP1SPSP2, "int2e_cg_sa10sp1spsp2", 4, 48, 3, NonHermitian,
+    int2e_cg_sa10sp1spsp2_cart, int2e_cg_sa10sp1spsp2_sph,
+    int2e_cg_sa10sp1spsp2_spinor, int2e_cg_sa10sp1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_GIAO_SA10SP1, "int2e_giao_sa10sp1", 4, 12, 3, NonHermitian,
+    int2e_giao_sa10sp1_cart, int2e_giao_sa10sp1_sph,
+    int2e_giao_sa10sp1_spinor, int2e_giao_sa10sp1_optimizer
+}
+integral_kind! {
+    INT2E_GIAO_SA10SP1SPSP2, "int2e_giao_sa10sp1spsp2", 4, 48, 3, NonHermitian,
+    int2e_giao_sa10sp1spsp2_cart, int2e_giao_sa10sp1spsp2_sph,
+    int2e_giao_sa10sp1spsp2_spinor, int2e_giao_sa10sp1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_G1, "int2e_g1", 4, 12, 3, NonHermitian,
+    int2e_g1_cart, int2e_g1_sph, int2e_g1_spinor, int2e_g1_optimizer
+}
+integral_kind! {
+    INT2E_SPGSP1, "int2e_spgsp1", 4, 12, 3, NonHermitian,
+    int2e_spgsp1_cart, int2e_spgsp1_sph, int2e_spgsp1_spinor, int2e_spgsp1_optimizer
+}
+integral_kind! {
+    INT2E_G1SPSP2, "int2e_g1spsp2", 4, 12, 3, NonHermitian,
+    int2e_g1spsp2_cart, int2e_g1spsp2_sph, int2e_g1spsp2_spinor, int2e_g1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_SPGSP1SPSP2, "int2e_spgsp1spsp2", 4, 48, 3, NonHermitian,
+    int2e_spgsp1spsp2_cart, int2e_spgsp1spsp2_sph,
+    int2e_spgsp1spsp2_spinor, int2e_spgsp1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_PP1, "int2e_pp1", 4, 1, 1, NonHermitian,
+    int2e_pp1_cart, int2e_pp1_sph, int2e_pp1_spinor, int2e_pp1_optimizer
+}
+integral_kind! {
+    INT2E_PP2, "int2e_pp2", 4, 1, 1, NonHermitian,
+    int2e_pp2_cart, int2e_pp2_sph, int2e_pp2_spinor, int2e_pp2_optimizer
+}
+integral_kind! {
+    INT2E_PP1PP2, "int2e_pp1pp2", 4, 1, 1, NonHermitian,
+    int2e_pp1pp2_cart, int2e_pp1pp2_sph, int2e_pp1pp2_spinor, int2e_pp1pp2_optimizer
+}
+integral_kind! {
+    INT1E_SPSPSP, "int1e_spspsp", 2, 4, 1, NonHermitian,
+    int1e_spspsp_cart, int1e_spspsp_sph, int1e_spspsp_spinor, int1e_spspsp_optimizer
+}
+integral_kind! {
+    INT1E_SPNUC, "int1e_spnuc", 2, 4, 1, NonHermitian,
+    int1e_spnuc_cart, int1e_spnuc_sph, int1e_spnuc_spinor, int1e_spnuc_optimizer
+}
+integral_kind! {
+    INT2E_SPV1, "int2e_spv1", 4, 4, 1, NonHermitian,
+    int2e_spv1_cart, int2e_spv1_sph, int2e_spv1_spinor, int2e_spv1_optimizer
+}
+integral_kind! {
+    INT2E_VSP1, "int2e_vsp1", 4, 4, 1, NonHermitian,
+    int2e_vsp1_cart, int2e_vsp1_sph, int2e_vsp1_spinor, int2e_vsp1_optimizer
+}
+integral_kind! {
+    INT2E_SPSP2, "int2e_spsp2", 4, 4, 1, NonHermitian,
+    int2e_spsp2_cart, int2e_spsp2_sph, int2e_spsp2_spinor, int2e_spsp2_optimizer
+}
+integral_kind! {
+    INT2E_SPV1SPV2, "int2e_spv1spv2", 4, 16, 1, NonHermitian,
+    int2e_spv1spv2_cart, int2e_spv1spv2_sph, int2e_spv1spv2_spinor, int2e_spv1spv2_optimizer
+}
+integral_kind! {
+    INT2E_VSP1SPV2, "int2e_vsp1spv2", 4, 16, 1, NonHermitian,
+    int2e_vsp1spv2_cart, int2e_vsp1spv2_sph, int2e_vsp1spv2_spinor, int2e_vsp1spv2_optimizer
+}
+integral_kind! {
+    INT2E_SPV1VSP2, "int2e_spv1vsp2", 4, 16, 1, NonHermitian,
+    int2e_spv1vsp2_cart, int2e_spv1vsp2_sph, int2e_spv1vsp2_spinor, int2e_spv1vsp2_optimizer
+}
+integral_kind! {
+    INT2E_VSP1VSP2, "int2e_vsp1vsp2", 4, 16, 1, NonHermitian,
+    int2e_vsp1vsp2_cart, int2e_vsp1vsp2_sph, int2e_vsp1vsp2_spinor, int2e_vsp1vsp2_optimizer
+}
+integral_kind! {
+    INT2E_SPV1SPSP2, "int2e_spv1spsp2", 4, 16, 1, NonHermitian,
+    int2e_spv1spsp2_cart, int2e_spv1spsp2_sph, int2e_spv1spsp2_spinor, int2e_spv1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_VSP1SPSP2, "int2e_vsp1spsp2", 4, 16, 1, NonHermitian,
+    int2e_vsp1spsp2_cart, int2e_vsp1spsp2_sph, int2e_vsp1spsp2_spinor, int2e_vsp1spsp2_optimizer
+}
+integral_kind! {
+    INT1E_IPOVLP, "int1e_ipovlp", 2, 3, 3, NonHermitian,
+    int1e_ipovlp_cart, int1e_ipovlp_sph, int1e_ipovlp_spinor, int1e_ipovlp_optimizer
+}
+integral_kind! {
+    INT1E_OVLPIP, "int1e_ovlpip", 2, 3, 3, NonHermitian,
+    int1e_ovlpip_cart, int1e_ovlpip_sph, int1e_ovlpip_spinor, int1e_ovlpip_optimizer
+}
+integral_kind! {
+    INT1E_IPKIN, "int1e_ipkin", 2, 3, 3, NonHermitian,
+    int1e_ipkin_cart, int1e_ipkin_sph, int1e_ipkin_spinor, int1e_ipkin_optimizer
+}
+integral_kind! {
+    INT1E_KINIP, "int1e_kinip", 2, 3, 3, NonHermitian,
+    int1e_kinip_cart, int1e_kinip_sph, int1e_kinip_spinor, int1e_kinip_optimizer
+}
+integral_kind! {
+    INT1E_IPNUC, "int1e_ipnuc", 2, 3, 3, NonHermitian,
+    int1e_ipnuc_cart, int1e_ipnuc_sph, int1e_ipnuc_spinor, int1e_ipnuc_optimizer
+}
+integral_kind! {
+    INT1E_IPRINV, "int1e_iprinv", 2, 3, 3, NonHermitian,
+    int1e_iprinv_cart, int1e_iprinv_sph, int1e_iprinv_spinor, int1e_iprinv_optimizer
+}
+integral_kind! {
+    INT1E_IPSPNUCSP, "int1e_ipspnucsp", 2, 12, 3, NonHermitian,
+    int1e_ipspnucsp_cart, int1e_ipspnucsp_sph, int1e_ipspnucsp_spinor, int1e_ipspnucsp_optimizer
+}
+integral_kind! {
+    INT1E_IPSPRINVSP, "int1e_ipsprinvsp", 2, 12, 3, NonHermitian,
+    int1e_ipsprinvsp_cart, int1e_ipsprinvsp_sph, int1e_ipsprinvsp_spinor, int1e_ipsprinvsp_optimizer
+}
+integral_kind! {
+    INT1E_IPPNUCP, "int1e_ippnucp", 2, 3, 3, NonHermitian,
+    int1e_ippnucp_cart, int1e_ippnucp_sph, int1e_ippnucp_spinor, int1e_ippnucp_optimizer
+}
+integral_kind! {
+    INT1E_IPPRINVP, "int1e_ipprinvp", 2, 3, 3, NonHermitian,
+    int1e_ipprinvp_cart, int1e_ipprinvp_sph, int1e_ipprinvp_spinor, int1e_ipprinvp_optimizer
+}
+integral_kind! {
+    INT2E_IP1, "int2e_ip1", 4, 3, 3, NonHermitian,
+    int2e_ip1_cart, int2e_ip1_sph, int2e_ip1_spinor, int2e_ip1_optimizer
+}
+integral_kind! {
+    INT2E_IP2, "int2e_ip2", 4, 3, 3, NonHermitian,
+    int2e_ip2_cart, int2e_ip2_sph, int2e_ip2_spinor, int2e_ip2_optimizer
+}
+integral_kind! {
+    INT2E_IPSPSP1, "int2e_ipspsp1", 4, 12, 3, NonHermitian,
+    int2e_ipspsp1_cart, int2e_ipspsp1_sph, int2e_ipspsp1_spinor, int2e_ipspsp1_optimizer
+}
+integral_kind! {
+    INT2E_IP1SPSP2, "int2e_ip1spsp2", 4, 12, 3, NonHermitian,
+    int2e_ip1spsp2_cart, int2e_ip1spsp2_sph, int2e_ip1spsp2_spinor, int2e_ip1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_IPSPSP1SPSP2, "int2e_ipspsp1spsp2", 4, 48, 3, NonHermitian,
+    int2e_ipspsp1spsp2_cart, int2e_ipspsp1spsp2_sph,
+    int2e_ipspsp1spsp2_spinor, int2e_ipspsp1spsp2_optimizer
+}
+integral_kind! {
+    INT2E_IPSRSR1, "int2e_ipsrsr1", 4, 12, 3, NonHermitian,
+    int2e_ipsrsr1_cart, int2e_ipsrsr1_sph, int2e_ipsrsr1_spinor, int2e_ipsrsr1_optimizer
+}
+integral_kind! {
+    INT2E_IP1SRSR2, "int2e_ip1srsr2", 4, 12, 3, NonHermitian,
+    int2e_ip1srsr2_cart, int2e_ip1srsr2_sph, int2e_ip1srsr2_spinor, int2e_ip1srsr2_optimizer
+}
+integral_kind! {
+    INT2E_IPSRSR1SRSR2, "int2e_ipsrsr1srsr2", 4, 48, 3, NonHermitian,
+    int2e_ipsrsr1srsr2_cart, int2e_ipsrsr1srsr2_sph,
+    int2e_ipsrsr1srsr2_spinor, int2e_ipsrsr1srsr2_optimizer
+}
+integral_kind! {
+    INT2E_SSP1SSP2, "int2e_ssp1ssp2", 4, 16, 1, NonHermitian,
+    int2e_ssp1ssp2_cart, int2e_ssp1ssp2_sph, int2e_ssp1ssp2_spinor, int2e_ssp1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_SSP1SPS2, "int2e_ssp1sps2", 4, 16, 1, NonHermitian,
+    int2e_ssp1sps2_cart, int2e_ssp1sps2_sph, int2e_ssp1sps2_spinor, int2e_ssp1sps2_optimizer
+}
+integral_kind! {
+    INT2E_SPS1SSP2, "int2e_sps1ssp2", 4, 16, 1, NonHermitian,
+    int2e_sps1ssp2_cart, int2e_sps1ssp2_sph, int2e_sps1ssp2_spinor, int2e_sps1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_SPS1SPS2, "int2e_sps1sps2", 4, 16, 1, NonHermitian,
+    int2e_sps1sps2_cart, int2e_sps1sps2_sph, int2e_sps1sps2_spinor, int2e_sps1sps2_optimizer
+}
+integral_kind! {
+    INT2E_CG_SSA10SSP2, "int2e_cg_ssa10ssp2", 4, 48, 3, NonHermitian,
+    int2e_cg_ssa10ssp2_cart, int2e_cg_ssa10ssp2_sph,
+    int2e_cg_ssa10ssp2_spinor, int2e_cg_ssa10ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GIAO_SSA10SSP2, "int2e_giao_ssa10ssp2", 4, 48, 3, NonHermitian,
+    int2e_giao_ssa10ssp2_cart, int2e_giao_ssa10ssp2_sph,
+    int2e_giao_ssa10ssp2_spinor, int2e_giao_ssa10ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GSSP1SSP2, "int2e_gssp1ssp2", 4, 48, 3, NonHermitian,
+    int2e_gssp1ssp2_cart, int2e_gssp1ssp2_sph, int2e_gssp1ssp2_spinor, int2e_gssp1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R1_SSP1SSP2, "int2e_gauge_r1_ssp1ssp2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r1_ssp1ssp2_cart, int2e_gauge_r1_ssp1ssp2_sph,
+    int2e_gauge_r1_ssp1ssp2_spinor, int2e_gauge_r1_ssp1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R1_SSP1SPS2, "int2e_gauge_r1_ssp1sps2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r1_ssp1sps2_cart, int2e_gauge_r1_ssp1sps2_sph,
+    int2e_gauge_r1_ssp1sps2_spinor, int2e_gauge_r1_ssp1sps2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R1_SPS1SSP2, "int2e_gauge_r1_sps1ssp2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r1_sps1ssp2_cart, int2e_gauge_r1_sps1ssp2_sph,
+    int2e_gauge_r1_sps1ssp2_spinor, int2e_gauge_r1_sps1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R1_SPS1SPS2, "int2e_gauge_r1_sps1sps2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r1_sps1sps2_cart, int2e_gauge_r1_sps1sps2_sph,
+    int2e_gauge_r1_sps1sps2_spinor, int2e_gauge_r1_sps1sps2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R2_SSP1SSP2, "int2e_gauge_r2_ssp1ssp2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r2_ssp1ssp2_cart, int2e_gauge_r2_ssp1ssp2_sph,
+    int2e_gauge_r2_ssp1ssp2_spinor, int2e_gauge_r2_ssp1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R2_SSP1SPS2, "int2e_gauge_r2_ssp1sps2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r2_ssp1sps2_cart, int2e_gauge_r2_ssp1sps2_sph,
+    int2e_gauge_r2_ssp1sps2_spinor, int2e_gauge_r2_ssp1sps2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R2_SPS1SSP2, "int2e_gauge_r2_sps1ssp2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r2_sps1ssp2_cart, int2e_gauge_r2_sps1ssp2_sph,
+    int2e_gauge_r2_sps1ssp2_spinor, int2e_gauge_r2_sps1ssp2_optimizer
+}
+integral_kind! {
+    INT2E_GAUGE_R2_SPS1SPS2, "int2e_gauge_r2_sps1sps2", 4, 16, 1, NonHermitian,
+    int2e_gauge_r2_sps1sps2_cart, int2e_gauge_r2_sps1sps2_sph,
+    int2e_gauge_r2_sps1sps2_spinor, int2e_gauge_r2_sps1sps2_optimizer
+}
+integral_kind! {
+    INT1E_IPIPOVLP, "int1e_ipipovlp", 2, 9, 9, NonHermitian,
+    int1e_ipipovlp_cart, int1e_ipipovlp_sph, int1e_ipipovlp_spinor, int1e_ipipovlp_optimizer
+}
+integral_kind! {
+    INT1E_IPOVLPIP, "int1e_ipovlpip", 2, 9, 9, NonHermitian,
+    int1e_ipovlpip_cart, int1e_ipovlpip_sph, int1e_ipovlpip_spinor, int1e_ipovlpip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPKIN, "int1e_ipipkin", 2, 9, 9, NonHermitian,
+    int1e_ipipkin_cart, int1e_ipipkin_sph, int1e_ipipkin_spinor, int1e_ipipkin_optimizer
+}
+integral_kind! {
+    INT1E_IPKINIP, "int1e_ipkinip", 2, 9, 9, NonHermitian,
+    int1e_ipkinip_cart, int1e_ipkinip_sph, int1e_ipkinip_spinor, int1e_ipkinip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPNUC, "int1e_ipipnuc", 2, 9, 9, NonHermitian,
+    int1e_ipipnuc_cart, int1e_ipipnuc_sph, int1e_ipipnuc_spinor, int1e_ipipnuc_optimizer
+}
+integral_kind! {
+    INT1E_IPNUCIP, "int1e_ipnucip", 2, 9, 9, NonHermitian,
+    int1e_ipnucip_cart, int1e_ipnucip_sph, int1e_ipnucip_spinor, int1e_ipnucip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPRINV, "int1e_ipiprinv", 2, 9, 9, NonHermitian,
+    int1e_ipiprinv_cart, int1e_ipiprinv_sph, int1e_ipiprinv_spinor, int1e_ipiprinv_optimizer
+}
+integral_kind! {
+    INT1E_IPRINVIP, "int1e_iprinvip", 2, 9, 9, NonHermitian,
+    int1e_iprinvip_cart, int1e_iprinvip_sph, int1e_iprinvip_spinor, int1e_iprinvip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPR, "int1e_ipipr", 2, 27, 27, NonHermitian,
+    int1e_ipipr_cart, int1e_ipipr_sph, int1e_ipipr_spinor, int1e_ipipr_optimizer
+}
+integral_kind! {
+    INT1E_IPRIP, "int1e_iprip", 2, 27, 27, NonHermitian,
+    int1e_iprip_cart, int1e_iprip_sph, int1e_iprip_spinor, int1e_iprip_optimizer
+}
+integral_kind! {
+    INT2E_IPIP1, "int2e_ipip1", 4, 9, 9, NonHermitian,
+    int2e_ipip1_cart, int2e_ipip1_sph, int2e_ipip1_spinor, int2e_ipip1_optimizer
+}
+integral_kind! {
+    INT2E_IPVIP1, "int2e_ipvip1", 4, 9, 9, NonHermitian,
+    int2e_ipvip1_cart, int2e_ipvip1_sph, int2e_ipvip1_spinor, int2e_ipvip1_optimizer
+}
+integral_kind! {
+    INT2E_IP1IP2, "int2e_ip1ip2", 4, 9, 9, NonHermitian,
+    int2e_ip1ip2_cart, int2e_ip1ip2_sph, int2e_ip1ip2_spinor, int2e_ip1ip2_optimizer
+}
+integral_kind! {
+    INT1E_IPIPPNUCP, "int1e_ipippnucp", 2, 9, 9, NonHermitian,
+    int1e_ipippnucp_cart, int1e_ipippnucp_sph, int1e_ipippnucp_spinor, int1e_ipippnucp_optimizer
+}
+integral_kind! {
+    INT1E_IPPNUCPIP, "int1e_ippnucpip", 2, 9, 9, NonHermitian,
+    int1e_ippnucpip_cart, int1e_ippnucpip_sph, int1e_ippnucpip_spinor, int1e_ippnucpip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPPRINVP, "int1e_ipipprinvp", 2, 9, 9, NonHermitian,
+    int1e_ipipprinvp_cart, int1e_ipipprinvp_sph, int1e_ipipprinvp_spinor, int1e_ipipprinvp_optimizer
+}
+integral_kind! {
+    INT1E_IPPRINVPIP, "int1e_ipprinvpip", 2, 9, 9, NonHermitian,
+    int1e_ipprinvpip_cart, int1e_ipprinvpip_sph, int1e_ipprinvpip_spinor, int1e_ipprinvpip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPSPNUCSP, "int1e_ipipspnucsp", 2, 36, 9, NonHermitian,
+    int1e_ipipspnucsp_cart, int1e_ipipspnucsp_sph,
+    int1e_ipipspnucsp_spinor, int1e_ipipspnucsp_optimizer
+}
+integral_kind! {
+    INT1E_IPSPNUCSPIP, "int1e_ipspnucspip", 2, 36, 9, NonHermitian,
+    int1e_ipspnucspip_cart, int1e_ipspnucspip_sph,
+    int1e_ipspnucspip_spinor, int1e_ipspnucspip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPSPRINVSP, "int1e_ipipsprinvsp", 2, 36, 9, NonHermitian,
+    int1e_ipipsprinvsp_cart, int1e_ipipsprinvsp_sph,
+    int1e_ipipsprinvsp_spinor, int1e_ipipsprinvsp_optimizer
+}
+integral_kind! {
+    INT1E_IPSPRINVSPIP, "int1e_ipsprinvspip", 2, 36, 9, NonHermitian,
+    int1e_ipsprinvspip_cart, int1e_ipsprinvspip_sph,
+    int1e_ipsprinvspip_spinor, int1e_ipsprinvspip_optimizer
+}
+integral_kind! {
+    INT2E_IPIP1IPIP2, "int2e_ipip1ipip2", 4, 81, 81, NonHermitian,
+    int2e_ipip1ipip2_cart, int2e_ipip1ipip2_sph, int2e_ipip1ipip2_spinor, int2e_ipip1ipip2_optimizer
+}
+integral_kind! {
+    INT2E_IPVIP1IPVIP2, "int2e_ipvip1ipvip2", 4, 81, 81, NonHermitian,
+    int2e_ipvip1ipvip2_cart, int2e_ipvip1ipvip2_sph,
+    int2e_ipvip1ipvip2_spinor, int2e_ipvip1ipvip2_optimizer
+}
+integral_kind! {
+    INT2C2E, "int2c2e", 2, 1, 1, Hermitian,
+    int2c2e_cart, int2c2e_sph, int2c2e_spinor, int2c2e_optimizer
+}
+integral_kind! {
+    INT3C2E, "int3c2e", 3, 1, 1, Hermitian,
+    int3c2e_cart, int3c2e_sph, int3c2e_spinor, int3c2e_optimizer
+}
+integral_kind! {
+    INT3C2E_IP1, "int3c2e_ip1", 3, 3, 3, NonHermitian,
+    int3c2e_ip1_cart, int3c2e_ip1_sph, int3c2e_ip1_spinor, int3c2e_ip1_optimizer
+}
+integral_kind! {
+    INT3C2E_IP2, "int3c2e_ip2", 3, 3, 3, NonHermitian,
+    int3c2e_ip2_cart, int3c2e_ip2_sph, int3c2e_ip2_spinor, int3c2e_ip2_optimizer
+}
+integral_kind! {
+    INT3C2E_PVP1, "int3c2e_pvp1", 3, 1, 1, Hermitian,
+    int3c2e_pvp1_cart, int3c2e_pvp1_sph, int3c2e_pvp1_spinor, int3c2e_pvp1_optimizer
+}
+integral_kind! {
+    INT3C2E_PVXP1, "int3c2e_pvxp1", 3, 3, 3, AntiHermitian,
+    int3c2e_pvxp1_cart, int3c2e_pvxp1_sph, int3c2e_pvxp1_spinor, int3c2e_pvxp1_optimizer
+}
+integral_kind! {
+    INT2C2E_IP1, "int2c2e_ip1", 2, 3, 3, NonHermitian,
+    int2c2e_ip1_cart, int2c2e_ip1_sph, int2c2e_ip1_spinor, int2c2e_ip1_optimizer
+}
+integral_kind! {
+    INT2C2E_IP2, "int2c2e_ip2", 2, 3, 3, NonHermitian,
+    int2c2e_ip2_cart, int2c2e_ip2_sph, int2c2e_ip2_spinor, int2c2e_ip2_optimizer
+}
+integral_kind! {
+    INT3C2E_IG1, "int3c2e_ig1", 3, 3, 3, NonHermitian,
+    int3c2e_ig1_cart, int3c2e_ig1_sph, int3c2e_ig1_spinor, int3c2e_ig1_optimizer
+}
+integral_kind! {
+    INT3C2E_SPSP1, "int3c2e_spsp1", 3, 4, 1, NonHermitian,
+    int3c2e_spsp1_cart, int3c2e_spsp1_sph, int3c2e_spsp1_spinor, int3c2e_spsp1_optimizer
+}
+integral_kind! {
+    INT3C2E_IPSPSP1, "int3c2e_ipspsp1", 3, 12, 3, NonHermitian,
+    int3c2e_ipspsp1_cart, int3c2e_ipspsp1_sph, int3c2e_ipspsp1_spinor, int3c2e_ipspsp1_optimizer
+}
+integral_kind! {
+    INT3C2E_SPSP1IP2, "int3c2e_spsp1ip2", 3, 12, 3, NonHermitian,
+    int3c2e_spsp1ip2_cart, int3c2e_spsp1ip2_sph, int3c2e_spsp1ip2_spinor, int3c2e_spsp1ip2_optimizer
+}
+integral_kind! {
+    INT3C2E_IPIP1, "int3c2e_ipip1", 3, 9, 9, NonHermitian,
+    int3c2e_ipip1_cart, int3c2e_ipip1_sph, int3c2e_ipip1_spinor, int3c2e_ipip1_optimizer
+}
+integral_kind! {
+    INT3C2E_IPIP2, "int3c2e_ipip2", 3, 9, 9, NonHermitian,
+    int3c2e_ipip2_cart, int3c2e_ipip2_sph, int3c2e_ipip2_spinor, int3c2e_ipip2_optimizer
+}
+integral_kind! {
+    INT3C2E_IPVIP1, "int3c2e_ipvip1", 3, 9, 9, NonHermitian,
+    int3c2e_ipvip1_cart, int3c2e_ipvip1_sph, int3c2e_ipvip1_spinor, int3c2e_ipvip1_optimizer
+}
+integral_kind! {
+    INT3C2E_IP1IP2, "int3c2e_ip1ip2", 3, 9, 9, NonHermitian,
+    int3c2e_ip1ip2_cart, int3c2e_ip1ip2_sph, int3c2e_ip1ip2_spinor, int3c2e_ip1ip2_optimizer
+}
+integral_kind! {
+    INT2C2E_IPIP1, "int2c2e_ipip1", 2, 9, 9, NonHermitian,
+    int2c2e_ipip1_cart, int2c2e_ipip1_sph, int2c2e_ipip1_spinor, int2c2e_ipip1_optimizer
+}
+integral_kind! {
+    INT2C2E_IP1IP2, "int2c2e_ip1ip2", 2, 9, 9, NonHermitian,
+    int2c2e_ip1ip2_cart, int2c2e_ip1ip2_sph, int2c2e_ip1ip2_spinor, int2c2e_ip1ip2_optimizer
+}
+integral_kind! {
+    INT3C1E_P2, "int3c1e_p2", 3, 1, 1, NonHermitian,
+    int3c1e_p2_cart, int3c1e_p2_sph, int3c1e_p2_spinor, int3c1e_p2_optimizer
+}
+integral_kind! {
+    INT3C1E_IPRINV, "int3c1e_iprinv", 3, 3, 3, NonHermitian,
+    int3c1e_iprinv_cart, int3c1e_iprinv_sph, int3c1e_iprinv_spinor, int3c1e_iprinv_optimizer
+}
+integral_kind! {
+    INT3C1E_IP1, "int3c1e_ip1", 3, 3, 3, NonHermitian,
+    int3c1e_ip1_cart, int3c1e_ip1_sph, int3c1e_ip1_spinor, int3c1e_ip1_optimizer
+}
+integral_kind! {
+    INT1E_IPIPIPNUC, "int1e_ipipipnuc", 2, 27, 27, NonHermitian,
+    int1e_ipipipnuc_cart, int1e_ipipipnuc_sph, int1e_ipipipnuc_spinor, int1e_ipipipnuc_optimizer
+}
+integral_kind! {
+    INT1E_IPIPIPRINV, "int1e_ipipiprinv", 2, 27, 27, NonHermitian,
+    int1e_ipipiprinv_cart, int1e_ipipiprinv_sph, int1e_ipipiprinv_spinor, int1e_ipipiprinv_optimizer
+}
+integral_kind! {
+    INT1E_IPIPNUCIP, "int1e_ipipnucip", 2, 27, 27, NonHermitian,
+    int1e_ipipnucip_cart, int1e_ipipnucip_sph, int1e_ipipnucip_spinor, int1e_ipipnucip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPRINVIP, "int1e_ipiprinvip", 2, 27, 27, NonHermitian,
+    int1e_ipiprinvip_cart, int1e_ipiprinvip_sph, int1e_ipiprinvip_spinor, int1e_ipiprinvip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPRINVIPIP, "int1e_ipiprinvipip", 2, 81, 81, NonHermitian,
+    int1e_ipiprinvipip_cart, int1e_ipiprinvipip_sph,
+    int1e_ipiprinvipip_spinor, int1e_ipiprinvipip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPIPRINVIP, "int1e_ipipiprinvip", 2, 81, 81, NonHermitian,
+    int1e_ipipiprinvip_cart, int1e_ipipiprinvip_sph,
+    int1e_ipipiprinvip_spinor, int1e_ipipiprinvip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPIPIPRINV, "int1e_ipipipiprinv", 2, 81, 81, NonHermitian,
+    int1e_ipipipiprinv_cart, int1e_ipipipiprinv_sph,
+    int1e_ipipipiprinv_spinor, int1e_ipipipiprinv_optimizer
+}
+integral_kind! {
+    INT1E_IPRINVR, "int1e_iprinvr", 2, 9, 9, NonHermitian,
+    int1e_iprinvr_cart, int1e_iprinvr_sph, int1e_iprinvr_spinor, int1e_iprinvr_optimizer
+}
+integral_kind! {
+    INT1E_IPRINVIPRIP, "int1e_iprinviprip", 2, 81, 81, NonHermitian,
+    int1e_iprinviprip_cart, int1e_iprinviprip_sph,
+    int1e_iprinviprip_spinor, int1e_iprinviprip_optimizer
+}
+integral_kind! {
+    INT1E_RINVIPIPRIP, "int1e_rinvipiprip", 2, 81, 81, NonHermitian,
+    int1e_rinvipiprip_cart, int1e_rinvipiprip_sph,
+    int1e_rinvipiprip_spinor, int1e_rinvipiprip_optimizer
+}
+integral_kind! {
+    INT1E_IPIPRINVRIP, "int1e_ipiprinvrip", 2, 81, 81, NonHermitian,
+    int1e_ipiprinvrip_cart, int1e_ipiprinvrip_sph,
+    int1e_ipiprinvrip_spinor, int1e_ipiprinvrip_optimizer
+}
+
+pub static INTEGRAL_KINDS: [IntegralKind; 188] = [
+    INT2E,
+    INT1E_OVLP,
+    INT1E_NUC,
+    INT1E_KIN,
+    INT1E_IA01P,
+    INT1E_GIAO_IRJXP,
+    INT1E_CG_IRXP,
+    INT1E_GIAO_A11PART,
+    INT1E_CG_A11PART,
+    INT1E_A01GP,
+    INT1E_IGKIN,
+    INT1E_IGOVLP,
+    INT1E_IGNUC,
+    INT1E_PNUCP,
+    INT1E_Z,
+    INT1E_ZZ,
+    INT1E_R,
+    INT1E_R2,
+    INT1E_R4,
+    INT1E_RR,
+    INT1E_RRR,
+    INT1E_RRRR,
+    INT1E_Z_ORIGJ,
+    INT1E_ZZ_ORIGJ,
+    INT1E_R_ORIGJ,
+    INT1E_RR_ORIGJ,
+    INT1E_R2_ORIGJ,
+    INT1E_R4_ORIGJ,
+    INT1E_P4,
+    INT1E_PRINVP,
+    INT1E_PRINVXP,
+    INT1E_PNUCXP,
+    INT1E_IRP,
+    INT1E_IRRP,
+    INT1E_IRPR,
+    INT1E_GGOVLP,
+    INT1E_GGKIN,
+    INT1E_GGNUC,
+    INT1E_GRJXP,
+    INT1E_RINV,
+    INT1E_DRINV,
+    INT2E_IG1,
+    INT2E_GG1,
+    INT2E_G1G2,
+    INT2E_P1VXP1,
+    INT2E_IP1V_RC1,
+    INT2E_IP1V_R1,
+    INT2E_IPVG1_XP1,
+    INT2E_IPVG2_XP1,
+    INT1E_INUC_RCXP,
+    INT1E_INUC_RXP,
+    INT1E_SIGMA,
+    INT1E_SPSIGMASP,
+    INT1E_SRSR,
+    INT1E_SR,
+    INT1E_SRSP,
+    INT1E_SPSP,
+    INT1E_SP,
+    INT1E_SPNUCSP,
+    INT1E_SPRINVSP,
+    INT1E_SRNUCSR,
+    INT1E_SPRSP,
+    INT1E_GOVLP,
+    INT1E_GNUC,
+    INT1E_CG_SA10SA01,
+    INT1E_CG_SA10SP,
+    INT1E_CG_SA10NUCSP,
+    INT1E_GIAO_SA10SA01,
+    INT1E_GIAO_SA10SP,
+    INT1E_GIAO_SA10NUCSP,
+    INT1E_SA01SP,
+    INT1E_SPGSP,
+    INT1E_SPGNUCSP,
+    INT1E_SPGSA01,
+    INT2E_SPSP1,
+    INT2E_SPSP1SPSP2,
+    INT2E_SRSR1,
+    INT2E_SRSR1SRSR2,
+    INT2E_CG_SA10SP1,
+    INT2E_CG_SA10SP1SPSP2,
+    INT2E_GIAO_SA10SP1,
+    INT2E_GIAO_SA10SP1SPSP2,
+    INT2E_G1,
+    INT2E_SPGSP1,
+    INT2E_G1SPSP2,
+    INT2E_SPGSP1SPSP2,
+    INT2E_PP1,
+    INT2E_PP2,
+    INT2E_PP1PP2,
+    INT1E_SPSPSP,
+    INT1E_SPNUC,
+    INT2E_SPV1,
+    INT2E_VSP1,
+    INT2E_SPSP2,
+    INT2E_SPV1SPV2,
+    INT2E_VSP1SPV2,
+    INT2E_SPV1VSP2,
+    INT2E_VSP1VSP2,
+    INT2E_SPV1SPSP2,
+    INT2E_VSP1SPSP2,
+    INT1E_IPOVLP,
+    INT1E_OVLPIP,
+    INT1E_IPKIN,
+    INT1E_KINIP,
+    INT1E_IPNUC,
+    INT1E_IPRINV,
+    INT1E_IPSPNUCSP,
+    INT1E_IPSPRINVSP,
+    INT1E_IPPNUCP,
+    INT1E_IPPRINVP,
+    INT2E_IP1,
+    INT2E_IP2,
+    INT2E_IPSPSP1,
+    INT2E_IP1SPSP2,
+    INT2E_IPSPSP1SPSP2,
+    INT2E_IPSRSR1,
+    INT2E_IP1SRSR2,
+    INT2E_IPSRSR1SRSR2,
+    INT2E_SSP1SSP2,
+    INT2E_SSP1SPS2,
+    INT2E_SPS1SSP2,
+    INT2E_SPS1SPS2,
+    INT2E_CG_SSA10SSP2,
+    INT2E_GIAO_SSA10SSP2,
+    INT2E_GSSP1SSP2,
+    INT2E_GAUGE_R1_SSP1SSP2,
+    INT2E_GAUGE_R1_SSP1SPS2,
+    INT2E_GAUGE_R1_SPS1SSP2,
+    INT2E_GAUGE_R1_SPS1SPS2,
+    INT2E_GAUGE_R2_SSP1SSP2,
+    INT2E_GAUGE_R2_SSP1SPS2,
+    INT2E_GAUGE_R2_SPS1SSP2,
+    INT2E_GAUGE_R2_SPS1SPS2,
+    INT1E_IPIPOVLP,
+    INT1E_IPOVLPIP,
+    INT1E_IPIPKIN,
+    INT1E_IPKINIP,
+    INT1E_IPIPNUC,
+    INT1E_IPNUCIP,
+    INT1E_IPIPRINV,
+    INT1E_IPRINVIP,
+    INT1E_IPIPR,
+    INT1E_IPRIP,
+    INT2E_IPIP1,
+    INT2E_IPVIP1,
+    INT2E_IP1IP2,
+    INT1E_IPIPPNUCP,
+    INT1E_IPPNUCPIP,
+    INT1E_IPIPPRINVP,
+    INT1E_IPPRINVPIP,
+    INT1E_IPIPSPNUCSP,
+    INT1E_IPSPNUCSPIP,
+    INT1E_IPIPSPRINVSP,
+    INT1E_IPSPRINVSPIP,
+    INT2E_IPIP1IPIP2,
+    INT2E_IPVIP1IPVIP2,
+    INT2C2E,
+    INT3C2E,
+    INT3C2E_IP1,
+    INT3C2E_IP2,
+    INT3C2E_PVP1,
+    INT3C2E_PVXP1,
+    INT2C2E_IP1,
+    INT2C2E_IP2,
+    INT3C2E_IG1,
+    INT3C2E_SPSP1,
+    INT3C2E_IPSPSP1,
+    INT3C2E_SPSP1IP2,
+    INT3C2E_IPIP1,
+    INT3C2E_IPIP2,
+    INT3C2E_IPVIP1,
+    INT3C2E_IP1IP2,
+    INT2C2E_IPIP1,
+    INT2C2E_IP1IP2,
+    INT3C1E_P2,
+    INT3C1E_IPRINV,
+    INT3C1E_IP1,
+    INT1E_IPIPIPNUC,
+    INT1E_IPIPIPRINV,
+    INT1E_IPIPNUCIP,
+    INT1E_IPIPRINVIP,
+    INT1E_IPIPRINVIPIP,
+    INT1E_IPIPIPRINVIP,
+    INT1E_IPIPIPIPRINV,
+    INT1E_IPRINVR,
+    INT1E_IPRINVIPRIP,
+    INT1E_RINVIPIPRIP,
+    INT1E_IPIPRINVRIP,
+];
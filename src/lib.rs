@@ -3,45 +3,1493 @@ pub mod cint;
 
 #[cfg(test)]
 mod tests {
+    use crate::cint::{
+        cdata::CintDate,
+        intor::{Intor, IntorFunc},
+    };
+
+    const WATER_XYZ: &str = "3
+
+        H -0.5  0.0  0.0
+        O  0.0  0.0  0.0
+        H  0.5  0.0  0.0
+        ";
+
+    /// STO-3G basis set file of the tests, `NWCHEM` written once to the temp directory.
+    fn sto3g_path() -> String {
+        static PATH: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+        PATH.get_or_init(|| {
+            let path = std::env::temp_dir().join("cint_sto3g.nw");
+            std::fs::write(&path, NWCHEM).unwrap();
+            path.to_str().unwrap().to_string()
+        })
+        .clone()
+    }
+
+    /// The molecule of `xyz_str` in STO-3G.
+    fn sto3g(xyz_str: &str) -> CintDate {
+        CintDate::fron_xyz(xyz_str, &sto3g_path()).unwrap()
+    }
+
+    fn water() -> CintDate {
+        sto3g(WATER_XYZ)
+    }
+
+    /// STO-3G of H and O in NWChem format, as the Basis Set Exchange writes it.
+    const NWCHEM: &str = "#  STO-3G  EMSL  Basis Set Exchange Library
+BASIS \"ao basis\" PRINT
+#BASIS SET: (3s) -> [1s]
+H    S
+      3.42525091             0.15432897
+      0.62391373             0.53532814
+      0.16885540             0.44463454
+#BASIS SET: (6s,3p) -> [2s,1p]
+O    S
+    130.7093200              0.15432897
+     23.8088610              0.53532814
+      6.4436083              0.44463454
+O    SP
+      5.0331513             -0.09996723             0.15591627
+      1.1695961              0.39951283             0.60768372
+      0.3803890              0.70011547             0.39195739
+END
+";
 
     #[test]
     fn test_rawdata() {
-        use crate::cint::{cdata::CintDate, libcint::int1e_ovlp_cart};
+        use crate::cint::{
+            libcint::{int1e_ovlp_cart, int1e_ovlp_sph, int1e_ovlp_spinor},
+            registry::INT1E_OVLP,
+            CintType,
+        };
         use std::collections::BTreeMap;
 
+        let cint_data = water();
+
+        // one block per representation, sized by the shell dims of its AO offsets
+        let intor_all = cint_data.gen_intor_all();
+        let block_dims = |cint_type: CintType| -> Vec<i32> {
+            let ao_loc = intor_all.ao_loc(cint_type);
+            [0, 2].iter().map(|i| ao_loc[i + 1] - ao_loc[*i]).collect()
+        };
+        let out = unsafe { intor_all.int_cart([0, 2], IntorFunc::new(Some(int1e_ovlp_cart), 1)) }
+            .unwrap();
+        let dims = block_dims(CintType::Cartesian);
+        assert_eq!(out.dims(), &dims);
+        assert_eq!(out.out().len(), dims.iter().product::<i32>() as usize);
+        let out =
+            unsafe { intor_all.int_sph([0, 2], IntorFunc::new(Some(int1e_ovlp_sph), 1)) }.unwrap();
+        let dims = block_dims(CintType::Spheric);
+        assert_eq!(out.dims(), &dims);
+        assert_eq!(out.out().len(), dims.iter().product::<i32>() as usize);
+        let out =
+            unsafe { intor_all.int_spinor([0, 2], IntorFunc::new(Some(int1e_ovlp_spinor), 1)) }
+                .unwrap();
+        let dims = block_dims(CintType::Spinor);
+        assert_eq!(out.dims(), &dims);
+        assert_eq!(out.out().len(), dims.iter().product::<i32>() as usize);
+        let kind = intor_all.int_kind_spinor(&INT1E_OVLP, [0, 2]).unwrap();
+        assert_eq!(kind.dims(), out.dims());
+
+        // the spinor overlap is hermitian, its diagonal the spheric one of each shell
+        let nao = intor_all.nao(CintType::Spinor) as usize;
+        assert_eq!(nao, 2 * intor_all.nao(CintType::Spheric) as usize);
+        let spinor = intor_all.intor_kind_spinor::<2>(&INT1E_OVLP, None).unwrap();
+        assert_eq!(spinor.out().len(), nao * nao);
+        let raw = unsafe {
+            intor_all.intor_spinor::<2>(None, IntorFunc::new(Some(int1e_ovlp_spinor), 1))
+        }
+        .unwrap();
+        assert_eq!(raw.dims(), spinor.dims());
+        let spheric = intor_all
+            .intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None)
+            .unwrap();
+        for i in 0..nao {
+            for j in 0..nao {
+                let (x, y) = (spinor[vec![i, j]], spinor[vec![j, i]]);
+                assert!((x.re - y.re).abs() < 1e-12 && (x.im + y.im).abs() < 1e-12);
+                let z = raw[vec![i, j]];
+                assert!((x.re - z.re).abs() < 1e-12 && (x.im - z.im).abs() < 1e-12);
+            }
+        }
+        let (ao_spinor, ao_spheric) = (
+            intor_all.ao_loc(CintType::Spinor),
+            intor_all.ao_loc(CintType::Spheric),
+        );
+        for ish in 0..intor_all.nbas() as usize {
+            let norm = spheric[vec![ao_spheric[ish] as usize; 2]];
+            for i in ao_spinor[ish] as usize..ao_spinor[ish + 1] as usize {
+                let x = spinor[vec![i, i]];
+                assert!((x.re - norm).abs() < 1e-10 && x.im.abs() < 1e-12);
+            }
+        }
+
+        //
+        let intor_atm = cint_data.gen_intor(vec![0, 2]).unwrap();
+        assert_sub_block(&intor_all, &intor_atm);
+
         //
-        let work_path = std::env::current_dir().unwrap();
+        let mut some_bas = BTreeMap::new();
+        some_bas.insert(0, vec![0]);
+        some_bas.insert(2, vec![0, 2]);
+        let intor_bas = cint_data.gen_intor_select(some_bas).unwrap();
+        assert_eq!(intor_bas.nbas(), 3);
+        assert_eq!(intor_bas.shell_atom(2), Ok(2));
+        assert!(intor_bas.shell_atom(3).is_err());
+        assert!(intor_bas.global_shell(3).is_err());
+        assert_sub_block(&intor_all, &intor_bas);
+    }
+
+    /// Every block of `sub` must equal the matching block of `full`, for ovlp and nuc.
+    fn assert_sub_block(full: &Intor, sub: &Intor) {
+        use crate::cint::{
+            registry::{INT1E_NUC, INT1E_OVLP},
+            CintType,
+        };
+
+        let full_loc = full.ao_loc(CintType::Spheric);
+        let sub_loc = sub.ao_loc(CintType::Spheric);
+        for kind in [&INT1E_OVLP, &INT1E_NUC] {
+            let full_mat = full.intor_kind::<2>(kind, CintType::Spheric, None).unwrap();
+            let sub_mat = sub.intor_kind::<2>(kind, CintType::Spheric, None).unwrap();
+            for ish in 0..sub.nbas() as usize {
+                for jsh in 0..sub.nbas() as usize {
+                    let gi = full_loc[sub.global_shell(ish).unwrap()] as usize;
+                    let gj = full_loc[sub.global_shell(jsh).unwrap()] as usize;
+                    for i in 0..(sub_loc[ish + 1] - sub_loc[ish]) as usize {
+                        for j in 0..(sub_loc[jsh + 1] - sub_loc[jsh]) as usize {
+                            let x =
+                                sub_mat[vec![sub_loc[ish] as usize + i, sub_loc[jsh] as usize + j]];
+                            assert!((x - full_mat[vec![gi + i, gj + j]]).abs() < 1e-12);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_intor_assemble() {
+        use crate::cint::{libcint::int1e_ovlp_sph, CintType};
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        let ao_loc = intor.ao_loc(CintType::Spheric);
+        let nao = intor.nao(CintType::Spheric) as usize;
+
+        let ovlp =
+            unsafe { intor.intor_sph::<2>(None, IntorFunc::new(Some(int1e_ovlp_sph), 1)) }.unwrap();
+        assert_eq!(ovlp.dims(), &vec![nao as i32; 2]);
 
-        let basis_path = format!(
-            "{}/basis_set_exchange/basis_set_exchange/data/sto/STO-3G.1.json",
-            work_path.to_str().unwrap()
+        for ish in 0..intor.nbas() {
+            for jsh in 0..intor.nbas() {
+                let blk =
+                    unsafe { intor.int_sph([ish, jsh], IntorFunc::new(Some(int1e_ovlp_sph), 1)) }
+                        .unwrap();
+                let (i0, j0) = (ao_loc[ish as usize] as usize, ao_loc[jsh as usize] as usize);
+                for i in 0..blk.dims()[0] as usize {
+                    for j in 0..blk.dims()[1] as usize {
+                        assert_eq!(blk[vec![i, j]], ovlp[vec![i0 + i, j0 + j]]);
+                    }
+                }
+            }
+        }
+
+        for i in 0..nao {
+            for j in 0..nao {
+                assert!((ovlp[vec![i, j]] - ovlp[vec![j, i]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_intor_packed() {
+        use crate::cint::{
+            libcint::{int1e_ovlp_sph, int2e_sph},
+            CintType,
+        };
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+
+        let ovlp =
+            unsafe { intor.intor_sph::<2>(None, IntorFunc::new(Some(int1e_ovlp_sph), 1)) }.unwrap();
+        let ovlp_s2 =
+            unsafe { intor.intor_s2(CintType::Spheric, IntorFunc::new(Some(int1e_ovlp_sph), 1)) }
+                .unwrap();
+        let nao = ovlp_s2.nao() as usize;
+        assert_eq!(ovlp_s2.out().len(), nao * (nao + 1) / 2);
+        assert_eq!(ovlp_s2.unpack().out(), ovlp.out());
+
+        let eri =
+            unsafe { intor.intor_sph::<4>(None, IntorFunc::new(Some(int2e_sph), 1)) }.unwrap();
+        let eri_s4 =
+            unsafe { intor.intor_s4(CintType::Spheric, IntorFunc::new(Some(int2e_sph), 1)) }
+                .unwrap();
+        let eri_s8 =
+            unsafe { intor.intor_s8(CintType::Spheric, IntorFunc::new(Some(int2e_sph), 1)) }
+                .unwrap();
+        let npair = nao * (nao + 1) / 2;
+        assert_eq!(eri_s8.out().len(), npair * (npair + 1) / 2);
+        for (packed, full) in [eri_s4.unpack(), eri_s8.unpack()]
+            .iter()
+            .flat_map(|x| x.out().iter().zip(eri.out().iter()))
+        {
+            assert!((packed - full).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_optimizer() {
+        use crate::cint::{libcint::int2e_sph, registry::INT2E, CintType};
+
+        let cint_data = water();
+        let mut intor = cint_data.gen_intor_all();
+        let eri = unsafe { intor.intor_s8(CintType::Spheric, IntorFunc::new(Some(int2e_sph), 1)) }
+            .unwrap();
+
+        intor.set_optimizer(&INT2E).unwrap();
+        assert!(intor.optimizer().unwrap().is_for(INT2E.optimizer));
+        let eri_opt =
+            unsafe { intor.intor_s8(CintType::Spheric, IntorFunc::new(Some(int2e_sph), 1)) }
+                .unwrap();
+        for (x, y) in eri.out().iter().zip(eri_opt.out().iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_int_into() {
+        use crate::cint::libcint::int2e_sph;
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        assert!(
+            unsafe { intor.max_cache_size::<4>(IntorFunc::new(Some(int2e_sph), 1)) }.unwrap() > 0
+        );
+
+        let mut buf = vec![0.0; 256];
+        let shls = [1, 0, 2, 1];
+        let dims =
+            unsafe { intor.int_sph_into(shls, IntorFunc::new(Some(int2e_sph), 1), &mut buf) }
+                .unwrap();
+        let out = unsafe { intor.int_sph(shls, IntorFunc::new(Some(int2e_sph), 1)) }.unwrap();
+        assert_eq!(dims.to_vec(), *out.dims());
+        assert_eq!(&buf[..out.out().len()], &out.out()[..]);
+    }
+
+    #[test]
+    fn test_intor_ncomp() {
+        use crate::cint::{libcint::int1e_r_sph, CintType};
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        let int_r = IntorFunc::new(Some(int1e_r_sph), 3);
+
+        let r = unsafe { intor.intor_sph::<2>(None, int_r) }.unwrap();
+        let nao = intor.nao(CintType::Spheric) as usize;
+        assert_eq!(r.ncomp(), 3);
+        assert_eq!(r.out().len(), 3 * nao * nao);
+        assert_eq!(r[vec![1, 0, 2]], r.comp(2)[1]);
+
+        let blk = unsafe { intor.int_sph([1, 1], int_r) }.unwrap();
+        assert_eq!(
+            blk.out().len(),
+            3 * blk.dims()[0] as usize * blk.dims()[1] as usize
         );
 
+        let r_s2 = unsafe { intor.intor_s2(CintType::Spheric, int_r) }.unwrap();
+        assert_eq!(r_s2.out().len(), 3 * nao * (nao + 1) / 2);
+        for (x, y) in r_s2.unpack().out().iter().zip(r.out().iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_intor_kind() {
+        use crate::cint::{
+            intor::Symmetry,
+            libcint::int1e_ovlp_sph,
+            registry::{INT1E_IPOVLP, INT1E_OVLP, INT2E},
+            CintType,
+        };
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+
+        let ovlp = intor
+            .intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None)
+            .unwrap();
+        let ovlp_raw =
+            unsafe { intor.intor_sph::<2>(None, IntorFunc::new(Some(int1e_ovlp_sph), 1)) }.unwrap();
+        assert_eq!(ovlp.out(), ovlp_raw.out());
+
+        let ip = intor
+            .int_kind(&INT1E_IPOVLP, CintType::Cartesian, [1, 2])
+            .unwrap();
+        assert_eq!(ip.ncomp(), 3);
+
+        let eri = intor
+            .intor_kind_packed::<4>(&INT2E, CintType::Spheric, Symmetry::S8)
+            .unwrap();
+        assert_eq!(eri.nao(), intor.nao(CintType::Spheric));
+    }
+
+    #[test]
+    fn test_intor_kind_arity() {
+        use crate::cint::{error::CintError, registry::INT2E, CintType};
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        assert!(matches!(
+            intor.int_kind(&INT2E, CintType::Spheric, [0, 1]),
+            Err(CintError::Arity { ncenter: 4, .. })
+        ));
+        assert!(matches!(
+            intor.int_kind(&INT2E, CintType::Spheric, [0, 1, 2, 99]),
+            Err(CintError::ShellOutOfRange { shell: 99, .. })
+        ));
+    }
+
+    #[test]
+    fn test_general_contraction() {
+        use crate::cint::{
+            rawdata::{CintAtomGroup, CGTO},
+            registry::INT1E_OVLP,
+            CintType,
+        };
+
+        let exp = vec![13.01, 1.962, 0.4446, 0.122];
+        let coeff = vec![
+            vec![0.0196850, 0.1379770, 0.4781480, 0.5012400],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ];
+        let p = CGTO {
+            kappa_of: 0,
+            angl: vec![1],
+            exp: vec![0.727],
+            coeff: vec![vec![1.0]],
+        };
+        let general = vec![
+            CGTO {
+                kappa_of: 0,
+                angl: vec![0],
+                exp: exp.clone(),
+                coeff: coeff.clone(),
+            }
+            .norm(),
+            p.clone().norm(),
+        ];
+        let split = coeff
+            .iter()
+            .map(|row| {
+                CGTO {
+                    kappa_of: 0,
+                    angl: vec![0],
+                    exp: exp.clone(),
+                    coeff: vec![row.clone()],
+                }
+                .norm()
+            })
+            .chain([p.norm()])
+            .collect();
+
+        let coords = vec![[0.0, 0.0, 0.0], [0.0, 0.0, 1.4]];
+        let data = |basis| {
+            CintDate::new(vec![CintAtomGroup::new(
+                Some(basis),
+                1,
+                0,
+                0.0,
+                0.0,
+                coords.clone(),
+            )])
+            .unwrap()
+        };
+        let (general, split) = (data(general), data(split));
+        let (general, split) = (general.gen_intor_all(), split.gen_intor_all());
+        assert_eq!((general.nbas(), split.nbas()), (4, 6));
+        assert_eq!(general.nao(CintType::Spheric), split.nao(CintType::Spheric));
+
+        let s_general = general
+            .intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None)
+            .unwrap();
+        let s_split = split
+            .intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None)
+            .unwrap();
+        for (x, y) in s_general.out().iter().zip(s_split.out().iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_contracted_norm() {
+        use crate::cint::{rawdata::Normalization, registry::INT1E_OVLP, CintType};
+
+        let cint_data =
+            CintDate::fron_xyz_with(WATER_XYZ, &sto3g_path(), Normalization::Contracted).unwrap();
+        let intor = cint_data.gen_intor_all();
+        let ovlp = intor
+            .intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None)
+            .unwrap();
+        for i in 0..intor.nao(CintType::Spheric) as usize {
+            assert!((ovlp[vec![i, i]] - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_frac_charge() {
+        use crate::cint::{
+            rawdata::{AtomGroup, CintAtomGroup},
+            registry::INT1E_NUC,
+            CintType, NUC_FRAC_CHARGE,
+        };
+
+        let point = water();
+        let groups: Vec<CintAtomGroup> = point
+            .atom_groups()
+            .iter()
+            .map(|atoms| {
+                let mut atoms = atoms.clone();
+                *atoms.nuc_mod_of_mut() = NUC_FRAC_CHARGE as u8;
+                *atoms.frac_charge_mut() = *atoms.charge_of() as f64;
+                atoms
+            })
+            .collect();
+        let frac = CintDate::new(groups).unwrap();
+        assert_eq!(
+            (frac.frac_charge(0), frac.frac_charge(2)),
+            (Ok(1.0), Ok(8.0))
+        );
+
+        let nuc_point = point.gen_intor_all();
+        let nuc_point = nuc_point
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let nuc_frac = frac.gen_intor_all();
+        let nuc_frac = nuc_frac
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        for (x, y) in nuc_point.out().iter().zip(nuc_frac.out().iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        // fractional charges are not smeared
+        let mut gauss = frac.clone();
+        gauss.use_gaussian_nuc();
+        let groups = gauss.atom_groups().iter();
+        assert!(groups
+            .map(|atoms| *atoms.nuc_mod_of())
+            .all(|m| m == NUC_FRAC_CHARGE as u8));
+        assert_eq!(gauss.zeta(2), Ok(0.0));
+    }
+
+    #[test]
+    fn test_gaussian_nuc() {
+        use crate::cint::{nucmod::gaussian_nuc_zeta, registry::INT1E_NUC, CintType};
+
+        // r(H) = 1.406 fm
+        assert!((gaussian_nuc_zeta(1) / 2.1248e9 - 1.0).abs() < 1e-4);
+
+        let point = water();
+        let mut gauss = point.clone();
+        gauss.use_gaussian_nuc();
+        assert_eq!(point.zeta(2), Ok(0.0));
+        assert_eq!(gauss.zeta(2), Ok(gaussian_nuc_zeta(8)));
+        assert!(gauss.zeta(3).is_err());
+
+        let nuc_point = point.gen_intor_all();
+        let nuc_point = nuc_point
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let nuc_gauss = gauss.gen_intor_all();
+        let nuc_gauss = nuc_gauss
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let diff = nuc_point
+            .out()
+            .iter()
+            .zip(nuc_gauss.out().iter())
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f64::max);
+        assert!(0.0 < diff && diff < 1e-4);
+    }
+
+    #[test]
+    fn test_ghost_atoms() {
+        use crate::cint::{
+            rawdata::{AtomGroup, CintAtomGroup},
+            registry::INT1E_NUC,
+            CintType,
+        };
+
+        let groups = CintAtomGroup::from_xyz(
+            "4\n\nX-O 0.0 0.0 0.0\nGh(H) 0.0 0.0 1.0\n@H 0.0 0.0 2.0\nH 0.0 0.0 3.0",
+            None,
+        )
+        .unwrap();
+        let groups: Vec<(u8, bool, usize)> = groups
+            .iter()
+            .map(|atoms| {
+                (
+                    *atoms.charge_of(),
+                    *atoms.ghost(),
+                    atoms.coordinates().len(),
+                )
+            })
+            .collect();
+        assert_eq!(groups, vec![(1, false, 1), (1, true, 2), (8, true, 1)]);
+
+        // counterpoise water: H, Gh(H), O
         let xyz_str = "3
 
-        H -0.5  0.0  0.0
+        Gh(H) -0.5  0.0  0.0
         O  0.0  0.0  0.0
         H  0.5  0.0  0.0
         ";
+        let cp = sto3g(xyz_str);
+        let full = water();
+        assert_eq!(
+            (0..3).map(|i| cp.is_ghost(i).unwrap()).collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+        let (cp, full) = (cp.gen_intor_all(), full.gen_intor_all());
+        assert_eq!(cp.nao(CintType::Spheric), full.nao(CintType::Spheric));
 
-        let cint_data = CintDate::fron_xyz(xyz_str, &basis_path);
+        // without one proton the nuclear attraction is weaker everywhere on the diagonal
+        let nuc_cp = cp
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let nuc_full = full
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        for i in 0..cp.nao(CintType::Spheric) as usize {
+            assert!(nuc_cp[vec![i, i]] > nuc_full[vec![i, i]]);
+        }
+    }
 
-        //
-        let intor_all = cint_data.gen_intor_all();
-        let out = unsafe { intor_all.int_cart([0, 2], Some(int1e_ovlp_cart)) };
-        println!("{:?}", out);
+    #[test]
+    fn test_point_charges() {
+        use crate::cint::{
+            cdata::PointCharge,
+            rawdata::AtomGroup,
+            registry::{INT1E_IPNUC, INT1E_IPRINV, INT1E_NUC, INT1E_RINV, INT3C2E, INT3C2E_IP1},
+            CintType,
+        };
 
-        //
-        let intor_atm = cint_data.gen_intor(vec![0, 2]);
-        let out = unsafe { intor_atm.int_cart([0, 1], Some(int1e_ovlp_cart)) };
-        println!("{:?}", out);
+        let xyz_str = "3
 
-        //
-        let mut some_bas = BTreeMap::new();
-        some_bas.insert(0, vec![0]);
-        some_bas.insert(2, vec![0]);
-        let intor_bas = cint_data.gen_intor_select(some_bas);
-        let out = unsafe { intor_bas.int_cart([0, 1], Some(int1e_ovlp_cart)) };
-        println!("{:?}", out);
+        Gh(H) -0.5  0.0  0.0
+        O  0.0  0.0  0.0
+        H  0.5  0.0  0.0
+        ";
+        // a unit charge on the ghost centre restores the missing proton
+        let mut cp = sto3g(xyz_str);
+        let ghost = cp.atom_groups()[1].coordinates()[0];
+        cp.set_point_charges(vec![PointCharge::new(ghost, 1.0)]);
+        let full = water();
+
+        let (cp, full) = (cp.gen_intor_all(), full.gen_intor_all());
+        let nuc_full = full
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let nuc_cp = cp.eval_nuc(CintType::Spheric, true).unwrap();
+        for (x, y) in nuc_cp.out().iter().zip(nuc_full.out().iter()) {
+            assert!((x - y).abs() < 1e-10);
+        }
+        let nuc_qm = cp.eval_nuc(CintType::Spheric, false).unwrap();
+        assert_ne!(nuc_qm.out(), nuc_cp.out());
+
+        // a very compact Gaussian charge acts like a point charge
+        let mut smeared = water();
+        smeared.set_point_charges(vec![
+            PointCharge::new([0.0, 2.0, 0.0], -0.8),
+            PointCharge::gaussian([0.0, -2.0, 0.0], 0.4, 1e12),
+        ]);
+        let mut point = water();
+        point.set_point_charges(vec![
+            PointCharge::new([0.0, 2.0, 0.0], -0.8),
+            PointCharge::new([0.0, -2.0, 0.0], 0.4),
+        ]);
+        let v_smeared = smeared
+            .gen_intor_all()
+            .embedding_potential(CintType::Spheric)
+            .unwrap();
+        let v_point = point
+            .gen_intor_all()
+            .intor_point_charges(&INT3C2E, CintType::Spheric)
+            .unwrap();
+        for (x, y) in v_smeared.out().iter().zip(v_point.out().iter()) {
+            assert!((x - y).abs() < 1e-8);
+        }
+
+        // the same potential as -q <i|1/r|j> with the rinv origin on every charge
+        let mut v_rinv = vec![0.0; v_point.out().len()];
+        for charge in point.point_charges().clone() {
+            let rinv = point
+                .with_rinv_origin(charge.coord, |data| {
+                    data.gen_intor_all()
+                        .intor_kind::<2>(&INT1E_RINV, CintType::Spheric, None)
+                        .unwrap()
+                })
+                .unwrap();
+            v_rinv
+                .iter_mut()
+                .zip(rinv.out())
+                .for_each(|(x, y)| *x -= charge.charge * y);
+        }
+        for (x, y) in v_rinv.iter().zip(v_point.out().iter()) {
+            assert!((x - y).abs() < 1e-10);
+        }
+        let rinv_point = point
+            .gen_intor_all()
+            .intor_rinv_point_charges(&INT1E_RINV, CintType::Spheric)
+            .unwrap();
+        for (x, y) in rinv_point.out().iter().zip(v_rinv.iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+        let rinv_smeared = smeared
+            .gen_intor_all()
+            .intor_rinv_point_charges(&INT1E_RINV, CintType::Spheric)
+            .unwrap();
+        for (x, y) in rinv_smeared.out().iter().zip(v_smeared.out()) {
+            assert!((x - y).abs() < 1e-8);
+        }
+
+        // and for a derivative of the rinv family, component by component
+        let mut iprinv = vec![0.0; 3 * v_rinv.len()];
+        for charge in point.point_charges().clone() {
+            let ip = point
+                .with_rinv_origin(charge.coord, |data| {
+                    data.gen_intor_all()
+                        .intor_kind::<2>(&INT1E_IPRINV, CintType::Spheric, None)
+                        .unwrap()
+                })
+                .unwrap();
+            iprinv
+                .iter_mut()
+                .zip(ip.out())
+                .for_each(|(x, y)| *x -= charge.charge * y);
+        }
+        let iprinv_point = point
+            .gen_intor_all()
+            .intor_rinv_point_charges(&INT1E_IPRINV, CintType::Spheric)
+            .unwrap();
+        assert_eq!(iprinv_point.ncomp(), 3);
+        for (x, y) in iprinv_point.out().iter().zip(iprinv.iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        // derivatives: the charge on the ghost centre restores the missing ipnuc term
+        let ipnuc_cp = cp
+            .intor_kind::<2>(&INT1E_IPNUC, CintType::Spheric, None)
+            .unwrap();
+        let ipnuc_mm = cp
+            .intor_point_charges(&INT3C2E_IP1, CintType::Spheric)
+            .unwrap();
+        let ipnuc_full = full
+            .intor_kind::<2>(&INT1E_IPNUC, CintType::Spheric, None)
+            .unwrap();
+        assert_eq!(ipnuc_mm.ncomp(), 3);
+        for ((x, y), z) in ipnuc_cp
+            .out()
+            .iter()
+            .zip(ipnuc_mm.out())
+            .zip(ipnuc_full.out())
+        {
+            assert!((x + y - z).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_scoped_env() {
+        use crate::cint::{
+            registry::{INT1E_NUC, INT1E_RINV, INT2E},
+            CintType,
+        };
+
+        let mut cint_data = water();
+        cint_data.env.set_rinv_zeta(0.5);
+        assert_eq!(cint_data.env.rinv_zeta(), 0.5);
+        assert_eq!(cint_data.env.range_omega(), 0.0);
+        cint_data.env.set_rinv_zeta(0.0);
+
+        // grids live after the env data, and a new set replaces the last one
+        cint_data.env.set_grids(&[[0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+        let ptr = cint_data.env.ptr_grids();
+        assert_eq!(cint_data.env.ngrids(), 2);
+        cint_data.env.set_grids(&[[1.0, 0.0, 0.0]]);
+        assert_eq!(cint_data.env.ptr_grids(), ptr);
+        assert_eq!(cint_data.env.grids(), [[1.0, 0.0, 0.0]]);
+        cint_data.env.set_grids(&[]);
+        assert_eq!(cint_data.env.ngrids(), 0);
+
+        // sum_A -Z_A <i|1/r_A|j> is the nuclear attraction
+        let nuc = cint_data.gen_intor_all();
+        let nuc = nuc
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let mut v = vec![0.0; nuc.out().len()];
+        for (iatm, charge) in [(0, 1.0), (1, 1.0), (2, 8.0)] {
+            let rinv = cint_data
+                .with_rinv_origin(iatm, |data| {
+                    data.gen_intor_all()
+                        .intor_kind::<2>(&INT1E_RINV, CintType::Spheric, None)
+                        .unwrap()
+                })
+                .unwrap();
+            v.iter_mut()
+                .zip(rinv.out().iter())
+                .for_each(|(x, y)| *x -= charge * y);
+        }
+        for (x, y) in v.iter().zip(nuc.out().iter()) {
+            assert!((x - y).abs() < 1e-10);
+        }
+        assert_eq!(cint_data.env.rinv_orig(), [0.0; 3]);
+        assert!(cint_data.with_rinv_origin(3, |_| ()).is_err());
+
+        // a panic inside the scope still restores the globals
+        let scoped = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            cint_data.with_rinv_zeta(0.5, |_| panic!("scoped"))
+        }));
+        assert!(scoped.is_err());
+        assert_eq!(cint_data.env.rinv_zeta(), 0.0);
+
+        let mut intor = cint_data.gen_intor_all();
+        let shls = [1, 1, 1, 1];
+        let full = intor.int_kind(&INT2E, CintType::Spheric, shls).unwrap();
+        let long = intor.with_range_omega(0.3, |intor| {
+            intor.int_kind(&INT2E, CintType::Spheric, shls).unwrap()
+        });
+        let short = intor.with_range_omega(-0.3, |intor| {
+            intor.int_kind(&INT2E, CintType::Spheric, shls).unwrap()
+        });
+        for ((f, l), s) in full.out().iter().zip(long.out()).zip(short.out()) {
+            assert!((f - l - s).abs() < 1e-10);
+        }
+        let again = intor.int_kind(&INT2E, CintType::Spheric, shls).unwrap();
+        assert_eq!(again.out(), full.out());
+    }
+
+    #[test]
+    fn test_set_coordinates() {
+        use crate::cint::{intor::Symmetry, registry::INT2E, CintType};
+
+        let xyz_str = "3
+
+        H -0.6  0.1  0.0
+        O  0.0  0.0  0.0
+        H  0.6  0.1  0.0
+        ";
+        let bent = sto3g(xyz_str);
+
+        let mut moved = water();
+        let coords = bent.coordinates();
+        moved.set_coordinates(&coords[..2]).unwrap_err();
+        moved.set_coordinates(&coords).unwrap();
+        assert_eq!(moved.coordinates(), coords);
+
+        let (bent, moved) = (bent.gen_intor_all(), moved.gen_intor_all());
+        let eri_bent = bent
+            .intor_kind_packed::<4>(&INT2E, CintType::Spheric, Symmetry::S8)
+            .unwrap();
+        let eri_moved = moved
+            .intor_kind_packed::<4>(&INT2E, CintType::Spheric, Symmetry::S8)
+            .unwrap();
+        assert_eq!(eri_bent.out(), eri_moved.out());
+
+        // an Intor with a cached optimizer, then a move and a new Intor with its own optimizer
+        let mut cint_data = water();
+        let before = cint_data
+            .gen_intor_all()
+            .intor_kind::<4>(&INT2E, CintType::Spheric, None)
+            .unwrap();
+        for (iatm, coord) in coords.iter().enumerate() {
+            cint_data.set_atom_coord(iatm, *coord).unwrap();
+        }
+        assert_eq!(cint_data.coordinates(), coords);
+        let after = cint_data
+            .gen_intor_all()
+            .intor_kind::<4>(&INT2E, CintType::Spheric, None)
+            .unwrap();
+        let fresh = sto3g(xyz_str)
+            .gen_intor_all()
+            .intor_kind::<4>(&INT2E, CintType::Spheric, None)
+            .unwrap();
+        assert_eq!(after.out(), fresh.out());
+        assert_ne!(before.out(), after.out());
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        use crate::cint::{cdata::PointCharge, error::CintError, registry::INT1E_NUC, CintType};
+
+        let mut cint_data = water();
+        cint_data.use_gaussian_nuc();
+        cint_data.set_point_charges(vec![PointCharge::new([0.0, 1.3, 0.0], -0.834)]);
+        let json = cint_data.to_json().unwrap();
+        let reloaded = CintDate::from_json(&json).unwrap();
+        assert_eq!(reloaded.to_json().unwrap(), json);
+
+        let (intor, reloaded) = (cint_data.gen_intor_all(), reloaded.gen_intor_all());
+        assert_eq!(
+            intor.eval_nuc(CintType::Spheric, true).unwrap().out(),
+            reloaded.eval_nuc(CintType::Spheric, true).unwrap().out()
+        );
+        assert_eq!(
+            intor
+                .intor_kind::<2>(&INT1E_NUC, CintType::Cartesian, None)
+                .unwrap()
+                .out(),
+            reloaded
+                .intor_kind::<2>(&INT1E_NUC, CintType::Cartesian, None)
+                .unwrap()
+                .out()
+        );
+
+        let old = json.replacen("\"version\":1", "\"version\":0", 1);
+        assert!(matches!(
+            CintDate::from_json(&old),
+            Err(CintError::CheckpointVersion { found: Some(0), .. })
+        ));
+        let corrupt = json.replacen("\"coord\":20", "\"coord\":100000", 1);
+        assert!(matches!(
+            CintDate::from_json(&corrupt),
+            Err(CintError::Checkpoint { .. })
+        ));
+        let overflow = json.replacen("\"coord\":20", "\"coord\":2147483646", 1);
+        assert!(matches!(
+            CintDate::from_json(&overflow),
+            Err(CintError::Checkpoint { .. })
+        ));
+        let mut missing: serde_json::Value = serde_json::from_str(&json).unwrap();
+        missing["cint_date"]["atom_groups"][0]["coordinates"]
+            .as_array_mut()
+            .unwrap()
+            .pop();
+        assert!(matches!(
+            CintDate::from_json(&missing.to_string()),
+            Err(CintError::Checkpoint { .. })
+        ));
+    }
+
+    /// `.npy` file as written by `numpy.save` (format 1.0, C order).
+    fn write_npy(path: &std::path::Path, descr: &str, shape: &str, data: &[u8]) {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+            descr, shape
+        );
+        while (10 + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend(data);
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn test_raw_arrays() {
+        use crate::cint::{
+            libcint::{NGRIDS, PTR_GRIDS},
+            registry::{INT1E_NUC, INT1E_OVLP},
+            CintType,
+        };
+
+        let cint_data = water();
+        let (atm, bas, env) = cint_data.to_raw();
+        assert_eq!((atm.len(), bas.len()), (3 * 6, 5 * 8));
+
+        let dir = std::env::temp_dir();
+        let paths = ["atm", "bas", "env"].map(|name| dir.join(format!("cint_water_{}.npy", name)));
+        let atm_i8: Vec<u8> = atm.iter().flat_map(|x| (*x as i64).to_le_bytes()).collect();
+        write_npy(&paths[0], "<i8", "(3, 6)", &atm_i8);
+        let bas_i4: Vec<u8> = bas.iter().flat_map(|x| x.to_le_bytes()).collect();
+        write_npy(&paths[1], "<i4", "(5, 8)", &bas_i4);
+        let env_f8: Vec<u8> = env.iter().flat_map(|x| x.to_le_bytes()).collect();
+        write_npy(&paths[2], "<f8", &format!("({},)", env.len()), &env_f8);
+        let paths = paths.map(|path| path.to_str().unwrap().to_string());
+
+        let from_raw = CintDate::from_raw(&atm, &bas, &env).unwrap();
+        let from_npy = CintDate::from_npy(&paths[0], &paths[1], &paths[2]).unwrap();
+        assert_eq!(from_raw.to_raw(), from_npy.to_raw());
+        assert_eq!(from_raw.atom_groups().len(), 2);
+        assert_eq!(from_raw.coordinates(), cint_data.coordinates());
+
+        let (intor, from_raw) = (cint_data.gen_intor_all(), from_raw.gen_intor_all());
+        for kind in [&INT1E_OVLP, &INT1E_NUC] {
+            let x = intor
+                .intor_kind::<2>(kind, CintType::Spheric, None)
+                .unwrap();
+            let y = from_raw
+                .intor_kind::<2>(kind, CintType::Spheric, None)
+                .unwrap();
+            assert_eq!(x.out(), y.out());
+        }
+
+        assert!(CintDate::from_raw(&atm[..17], &bas, &env).is_err());
+        assert!(CintDate::from_raw(&atm, &bas, &env[..30]).is_err());
+        let mut bad_atm = atm.clone();
+        bad_atm[2] = 256;
+        assert!(CintDate::from_raw(&bad_atm, &bas, &env).is_err());
+
+        // grids are copied over to the rebuilt env
+        let mut grid_env = env.clone();
+        grid_env[PTR_GRIDS as usize] = env.len() as f64;
+        grid_env[NGRIDS as usize] = 1.0;
+        grid_env.extend([0.0, 0.0, 1.0]);
+        let from_raw = CintDate::from_raw(&atm, &bas, &grid_env).unwrap();
+        assert_eq!(from_raw.env.grids(), [[0.0, 0.0, 1.0]]);
+        grid_env[NGRIDS as usize] = 1e6;
+        assert!(CintDate::from_raw(&atm, &bas, &grid_env).is_err());
+    }
+
+    #[test]
+    fn test_basis_formats() {
+        use crate::cint::{
+            basis_format::BasisFormat, error::CintError, rawdata::Normalization, rawdata::CGTO,
+            registry::INT1E_OVLP, CintType,
+        };
+
+        const GAUSSIAN94: &str = "! STO-3G  EMSL  Basis Set Exchange Library
+
+H     0
+S   3   1.00
+      3.42525091             0.15432897
+      0.62391373             0.53532814
+      0.16885540             0.44463454
+****
+O     0
+S   3   1.00
+    130.7093200              0.15432897
+     23.8088610              0.53532814
+      6.4436083              0.44463454
+SP   3   1.00
+      5.0331513D+00         -0.09996723D+00          0.15591627D+00
+      1.1695961             0.39951283             0.60768372
+      0.3803890             0.70011547             0.39195739
+****
+";
+
+        const MOLPRO: &str = "! STO-3G
+basis={
+!
+! hydrogen             (3s) -> [1s]
+s, H , 3.42525091, 0.62391373, 0.16885540
+c, 1.3, 0.15432897, 0.53532814, 0.44463454
+! oxygen             (6s,3p) -> [2s,1p]
+s, O , 130.7093200, 23.8088610, 6.4436083, 5.0331513, 1.1695961, 0.3803890
+c, 1.3, 0.15432897, 0.53532814, 0.44463454
+c, 4.6, -0.09996723, 0.39951283, 0.70011547
+p, O , 5.0331513, 1.1695961, 0.3803890
+c, 1.3, 0.15591627, 0.60768372, 0.39195739
+}
+";
+
+        const GENBAS: &str = "H:STO-3G
+STO-3G from the Basis Set Exchange
+
+  1
+  0
+  1
+  3
+
+  3.42525091  0.62391373  0.16885540
+
+  0.15432897
+  0.53532814
+  0.44463454
+
+O:STO-3G
+STO-3G from the Basis Set Exchange
+
+  2
+  0  1
+  2  1
+  6  3
+
+  130.7093200  23.8088610  6.4436083  5.0331513  1.1695961
+  0.3803890
+
+  0.15432897   0.00000000
+  0.53532814   0.00000000
+  0.44463454   0.00000000
+  0.00000000  -0.09996723
+  0.00000000   0.39951283
+  0.00000000   0.70011547
+
+  5.0331513  1.1695961  0.3803890
+
+  0.15591627
+  0.60768372
+  0.39195739
+
+";
+
+        const TURBOMOLE: &str = "$basis
+*
+h STO-3G
+*
+    3  s
+      3.42525091             0.15432897
+      0.62391373             0.53532814
+      0.16885540             0.44463454
+*
+o STO-3G
+*
+    3  s
+    130.7093200              0.15432897
+     23.8088610              0.53532814
+      6.4436083              0.44463454
+    3  s
+      5.0331513             -0.09996723
+      1.1695961              0.39951283
+      0.3803890              0.70011547
+    3  p
+      5.0331513              0.15591627
+      1.1695961              0.60768372
+      0.3803890              0.39195739
+*
+$end
+";
+
+        const ORCA: &str = "# STO-3G
+$DATA
+
+HYDROGEN
+S   3
+  1         3.42525091             0.15432897
+  2         0.62391373             0.53532814
+  3         0.16885540             0.44463454
+
+OXYGEN
+S   3
+  1       130.7093200              0.15432897
+  2        23.8088610              0.53532814
+  3         6.4436083              0.44463454
+L   3
+  1         5.0331513             -0.09996723             0.15591627
+  2         1.1695961              0.39951283             0.60768372
+  3         0.3803890              0.70011547             0.39195739
+
+$END
+";
+
+        // (angular momentum, nonzero (exponent, coefficient) pairs) of every contraction
+        fn contractions(shells: &[CGTO]) -> Vec<(u8, Vec<(f64, f64)>)> {
+            let mut rows: Vec<_> = shells
+                .iter()
+                .flat_map(|cgto| {
+                    cgto.coeff.iter().enumerate().map(|(ictr, row)| {
+                        let pairs = cgto.exp.iter().zip(row).filter(|(_, c)| **c != 0.0);
+                        let pairs = pairs.map(|(e, c)| (*e, *c)).collect();
+                        (cgto.angl_of_coeff(ictr), pairs)
+                    })
+                })
+                .collect();
+            rows.sort_by_key(|(angl, _)| *angl);
+            rows
+        }
+
+        let samples = [
+            (BasisFormat::NWChem, NWCHEM),
+            (BasisFormat::Gaussian94, GAUSSIAN94),
+            (BasisFormat::Molpro, MOLPRO),
+            (BasisFormat::Genbas, GENBAS),
+            (BasisFormat::Turbomole, TURBOMOLE),
+            (BasisFormat::Orca, ORCA),
+        ];
+        let reference = BasisFormat::NWChem
+            .parse(NWCHEM, Normalization::None)
+            .unwrap();
+        assert_eq!(reference.keys().collect::<Vec<_>>(), [&1, &8]);
+        assert_eq!(reference[&8][1].angl, [0, 1]);
+        for (format, sample) in samples {
+            assert_eq!(BasisFormat::detect(sample), Some(format));
+            let basis = format.parse(sample, Normalization::None).unwrap();
+            for (charge, shells) in &reference {
+                assert_eq!(contractions(&basis[charge]), contractions(shells));
+            }
+        }
+        // ECP sections are skipped, the valence shells around them are kept
+        const MOLPRO_ECP: &str = "basis={
+s, Rb, 2.0, 0.5
+c, 1.2, 0.3, 0.7
+ECP, rb, 28, 4;
+1; ! ul potential
+2, 1.0, -10.0;
+1; ! s-ul potential
+2, 3.0, 5.0;
+}
+basis={
+p, Rb, 0.4
+}
+";
+        const ORCA_ECP: &str = "NewGTO Rb
+S   2
+  1         2.0             0.3
+  2         0.5             0.7
+end
+NewECP Rb
+  N_core 28
+  lmax f
+  s 1
+   1    3.0    5.0   2
+end
+NewGTO Rb
+P   1
+  1         0.4             1.0
+end
+";
+        for (format, sample) in [
+            (BasisFormat::Molpro, MOLPRO_ECP),
+            (BasisFormat::Orca, ORCA_ECP),
+        ] {
+            let basis = format.parse(sample, Normalization::None).unwrap();
+            assert_eq!(
+                contractions(&basis[&37]),
+                [(0, vec![(2.0, 0.3), (0.5, 0.7)]), (1, vec![(0.4, 1.0)])]
+            );
+        }
+
+        assert_eq!(
+            BasisFormat::from_path("sto-3g.gbs"),
+            Some(BasisFormat::Gaussian94)
+        );
+        assert_eq!(
+            BasisFormat::from_path("/basis/GENBAS"),
+            Some(BasisFormat::Genbas)
+        );
+
+        let err = BasisFormat::NWChem.parse("BASIS\nH S\n 1.0 x\nEND", Normalization::None);
+        assert!(matches!(err, Err(CintError::BasisSyntax { line: 3, .. })));
+
+        let path = std::env::temp_dir().join("cint_sto3g.gbs");
+        std::fs::write(&path, GAUSSIAN94).unwrap();
+        let gaussian = CintDate::fron_xyz(WATER_XYZ, path.to_str().unwrap()).unwrap();
+        let nwchem = water();
+        let (x, y) = (gaussian.gen_intor_all(), nwchem.gen_intor_all());
+        let x = x.intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None);
+        let y = y.intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None);
+        for (x, y) in x.unwrap().out().iter().zip(y.unwrap().out()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_basis_writers() {
+        use crate::cint::{
+            basis_format::{read_basis, write_basis, BasisFormat},
+            rawdata::Normalization,
+        };
+
+        let basis = water().basis();
+        assert_eq!(basis.keys().collect::<Vec<_>>(), [&1, &8]);
+        for (format, ext) in [
+            (BasisFormat::BseJson, "json"),
+            (BasisFormat::NWChem, "nw"),
+            (BasisFormat::Gaussian94, "gbs"),
+        ] {
+            let path = std::env::temp_dir().join(format!("cint_sto3g_out.{}", ext));
+            let path = path.to_str().unwrap();
+            write_basis(path, &basis, Normalization::Primitive).unwrap();
+            let text = std::fs::read_to_string(path).unwrap();
+            assert_eq!(BasisFormat::detect(&text), Some(format));
+
+            let back = read_basis(path, Normalization::Primitive).unwrap();
+            assert_eq!(back.keys().collect::<Vec<_>>(), [&1, &8]);
+            for (shells, back) in basis.values().zip(back.values()) {
+                assert_eq!(shells.len(), back.len());
+                for (cgto, back) in shells.iter().zip(back) {
+                    assert_eq!((&cgto.angl, &cgto.exp), (&back.angl, &back.exp));
+                    let coeff = cgto.coeff.iter().flatten().zip(back.coeff.iter().flatten());
+                    coeff.for_each(|(x, y)| assert!((x - y).abs() < 1e-12 * x.abs()));
+                }
+            }
+        }
+
+        let text = BasisFormat::NWChem
+            .write(&basis, Normalization::None)
+            .unwrap();
+        assert!(text.contains("O    SP"));
+        assert!(BasisFormat::Molpro
+            .write(&basis, Normalization::None)
+            .is_err());
+
+        // contracted shells are written from the primitive ones and normalized on reading
+        assert!(BasisFormat::NWChem
+            .write(&basis, Normalization::Contracted)
+            .is_err());
+        let path = std::env::temp_dir().join("cint_sto3g_contracted.nw");
+        let path = path.to_str().unwrap();
+        write_basis(path, &basis, Normalization::Primitive).unwrap();
+        let back = read_basis(path, Normalization::Contracted).unwrap();
+        for (shells, back) in basis.values().zip(back.values()) {
+            for (cgto, back) in shells.iter().zip(back) {
+                let cgto = cgto.clone().denorm().norm_contracted();
+                let coeff = cgto.coeff.iter().flatten().zip(back.coeff.iter().flatten());
+                coeff.for_each(|(x, y)| assert!((x - y).abs() < 1e-12 * x.abs()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_basis_spec() {
+        use crate::cint::{
+            basis_spec::BasisSpec,
+            error::CintError,
+            rawdata::{AtomGroup, Normalization, CGTO},
+        };
+
+        let basis_path = sto3g_path();
+        let xyz_str = "3\n\nH1 -0.5 0.0 0.0\nO 0.0 0.0 0.0\nH2 0.5 0.0 0.0";
+        let norm = Normalization::Primitive;
+
+        // STO-3G with a diffuse s shell on hydrogen
+        let mut diffuse = water().basis();
+        diffuse.get_mut(&1).unwrap().push(
+            CGTO {
+                kappa_of: 0,
+                angl: vec![0],
+                exp: vec![0.03],
+                coeff: vec![vec![1.0]],
+            }
+            .norm(),
+        );
+
+        let nshl = |cint_data: &CintDate| -> Vec<(u8, usize, usize)> {
+            let groups = cint_data.atom_groups().iter();
+            groups
+                .map(|atoms| {
+                    let nshl = atoms.basis().as_ref().unwrap().len();
+                    (*atoms.charge_of(), atoms.coordinates().len(), nshl)
+                })
+                .collect()
+        };
+
+        let spec = BasisSpec::new(basis_path.as_str()).label("H2", diffuse.clone());
+        let by_label = CintDate::fron_xyz_spec(xyz_str, &spec, norm).unwrap();
+        assert_eq!(nshl(&by_label), [(1, 1, 1), (1, 1, 2), (8, 1, 3)]);
+        assert!(by_label.coordinates()[0][0] < 0.0 && by_label.coordinates()[1][0] > 0.0);
+
+        let spec = BasisSpec::new(basis_path.as_str()).atom(2, diffuse.clone());
+        let by_atom = CintDate::fron_xyz_spec(xyz_str, &spec, norm).unwrap();
+        assert_eq!(by_atom.to_raw(), by_label.to_raw());
+
+        let spec = BasisSpec::new(diffuse.clone()).element(8, basis_path.as_str());
+        let by_element = CintDate::fron_xyz_spec(xyz_str, &spec, norm).unwrap();
+        assert_eq!(nshl(&by_element), [(1, 2, 2), (8, 1, 3)]);
+
+        let spec = BasisSpec::new(basis_path.as_str()).element(1, basis_path.as_str());
+        let same = CintDate::fron_xyz_spec(xyz_str, &spec, norm).unwrap();
+        assert_eq!(nshl(&same), [(1, 2, 1), (8, 1, 3)]);
+
+        let spec = BasisSpec::default().element(1, diffuse);
+        let err = CintDate::fron_xyz_spec(xyz_str, &spec, norm).unwrap_err();
+        assert_eq!(err, CintError::MissingBasis { charge: 8 });
+    }
+
+    #[cfg(feature = "basis-library")]
+    #[test]
+    fn test_basis_library() {
+        use crate::cint::{error::CintError, library, registry::INT1E_OVLP, CintType};
+
+        assert!(library::names().any(|name| name == "def2-universal-jkfit"));
+        let named = CintDate::from_xyz_named(WATER_XYZ, "STO-3G").unwrap();
+        let nwchem = water();
+        let (x, y) = (named.gen_intor_all(), nwchem.gen_intor_all());
+        let x = x.intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None);
+        let y = y.intor_kind::<2>(&INT1E_OVLP, CintType::Spheric, None);
+        for (x, y) in x.unwrap().out().iter().zip(y.unwrap().out()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+        assert!(CintDate::from_xyz_named(WATER_XYZ, "def2-svp").is_ok());
+
+        let err = CintDate::from_xyz_named(WATER_XYZ, "def2-qzvppd").unwrap_err();
+        assert!(matches!(err, CintError::UnknownBasis { .. }));
+    }
+
+    #[test]
+    fn test_aux_basis() {
+        use crate::cint::{
+            auxbasis::{aug_etb_basis, ETB_BETA},
+            error::CintError,
+            rawdata::Normalization,
+            registry::{INT1E_NUC, INT2C2E, INT3C2E},
+            CintType,
+        };
+
+        let cint_data = water();
+        let aux_basis = aug_etb_basis(&cint_data.basis(), ETB_BETA, Normalization::None).unwrap();
+        for beta in [1.0, 0.5, f64::NAN] {
+            assert!(matches!(
+                cint_data.aux_etb(beta),
+                Err(CintError::EtbBeta { .. })
+            ));
+        }
+        let angls = |charge: u8| -> Vec<u8> {
+            aux_basis[&charge].iter().map(|cgto| cgto.angl[0]).collect()
+        };
+        assert_eq!(angls(1), [0; 5]);
+        assert_eq!(angls(8), [[0; 9].as_slice(), &[1; 7], &[2; 4]].concat());
+        // exponents from twice the most diffuse hydrogen exponent up by factors of two
+        let exps: Vec<f64> = aux_basis[&1].iter().map(|cgto| cgto.exp[0]).collect();
+        assert!((exps[4] - 2.0 * 0.16885540).abs() < 1e-8);
+        assert!((exps[0] - 16.0 * exps[4]).abs() < 1e-8);
+
+        let aux = cint_data.aux_etb(ETB_BETA).unwrap();
+        let combined = cint_data.with_aux(&aux).unwrap();
+        let (orbital, aux, combined) = (
+            cint_data.gen_intor_all(),
+            aux.gen_intor_all(),
+            combined.gen_intor_all(),
+        );
+        let (nbas, naux) = (orbital.nbas(), aux.nbas());
+        assert_eq!((nbas, naux), (5, 30));
+        assert_eq!(combined.nbas(), nbas + naux);
+
+        let j2c = aux
+            .intor_kind::<2>(&INT2C2E, CintType::Spheric, None)
+            .unwrap();
+        let j2c_combined = combined
+            .intor_kind::<2>(&INT2C2E, CintType::Spheric, Some([[nbas, nbas + naux]; 2]))
+            .unwrap();
+        assert_eq!(j2c.out(), j2c_combined.out());
+
+        // ghost auxiliary centres add no nuclear attraction
+        let nuc = orbital
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, None)
+            .unwrap();
+        let nuc_combined = combined
+            .intor_kind::<2>(&INT1E_NUC, CintType::Spheric, Some([[0, nbas]; 2]))
+            .unwrap();
+        for (x, y) in nuc.out().iter().zip(nuc_combined.out().iter()) {
+            assert!((x - y).abs() < 1e-10);
+        }
+
+        let slice = [[0, nbas], [0, nbas], [nbas, nbas + naux]];
+        let j3c = combined
+            .intor_kind::<3>(&INT3C2E, CintType::Spheric, Some(slice))
+            .unwrap();
+        let nao = orbital.nao(CintType::Spheric);
+        assert_eq!(j3c.dims(), &vec![nao, nao, aux.nao(CintType::Spheric)]);
+    }
+
+    #[test]
+    fn test_xyz_errors() {
+        use crate::cint::{error::CintError, rawdata::CintAtomGroup};
+
+        let err = CintAtomGroup::from_xyz("2\n\nH 0.0 0.0 0.0\nXx 0.0 0.0 1.0", None);
+        assert_eq!(
+            err.unwrap_err(),
+            CintError::UnknownElement {
+                line: 4,
+                symbol: "Xx".to_string()
+            }
+        );
+        let err = CintAtomGroup::from_xyz("1\n\nH 0.0 zero 0.0", None);
+        assert!(matches!(err, Err(CintError::Xyz { line: 3, .. })));
+        let err = CintAtomGroup::from_xyz("2\n\nH 0.0 0.0 0.0", None);
+        assert!(matches!(
+            err,
+            Err(CintError::AtomCount {
+                expected: 2,
+                found: 1
+            })
+        ));
+        let err = CintAtomGroup::from_xyz("1\n\nO 0.0 0.0 0.0", Some(Default::default()));
+        assert_eq!(err.unwrap_err(), CintError::MissingBasis { charge: 8 });
+    }
+
+    #[test]
+    fn test_kind_by_name() {
+        use crate::cint::{kind::IntegralKind, CintType};
+
+        let (kind, cint_type) = IntegralKind::by_name("int1e_kin_sph").unwrap();
+        assert_eq!(
+            (kind.name, cint_type),
+            ("int1e_kin", Some(CintType::Spheric))
+        );
+        let (kind, cint_type) = IntegralKind::by_name("int2e_ip1").unwrap();
+        assert_eq!((kind.ncenter, kind.ncomp, cint_type), (4, 3, None));
+
+        let (kind, cint_type) = IntegralKind::by_name("int3c2e_sph").unwrap();
+        assert_eq!((kind.name, kind.ncenter), ("int3c2e", 3));
+        assert_eq!(cint_type, Some(CintType::Spheric));
+        let err = IntegralKind::by_name("int3c2e_ip").unwrap_err();
+        assert!(err.to_string().contains("int3c2e_ip1"));
+
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        let nuc = intor.eval::<2>("int1e_nuc", CintType::Spheric).unwrap();
+        assert_eq!(nuc.dims()[0], intor.nao(CintType::Spheric));
+        assert!(intor.eval::<4>("int1e_nuc", CintType::Spheric).is_err());
+        assert!(intor
+            .eval::<2>("int1e_nuc_cart", CintType::Spheric)
+            .is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        use crate::cint::{
+            kind::Hermiticity,
+            registry::{INT1E_KIN, INT1E_OVLP, INT2E, INTEGRAL_KINDS},
+            CintType,
+        };
+        use std::collections::BTreeSet;
+
+        // every family with a `_sph` export in the bindings, `int1e_grids*` left out, plus the
+        // 2c2e and 3c2e integrals that `cint_funcs.h` declares through its typedefs
+        let bound: BTreeSet<&str> = include_str!("cint/libcint.rs")
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("pub fn "))
+            .filter_map(|line| line.split('(').next()?.strip_suffix("_sph"))
+            .filter(|name| name.starts_with("int") && !name.starts_with("int1e_grids"))
+            .chain(["int2c2e", "int3c2e"])
+            .collect();
+        let listed: BTreeSet<&str> = INTEGRAL_KINDS.iter().map(|kind| kind.name).collect();
+        assert_eq!(listed.len(), INTEGRAL_KINDS.len());
+        assert_eq!(listed, bound);
+
+        for kind in [&INT1E_OVLP, &INT1E_KIN, &INT2E] {
+            assert_eq!(kind.hermi, Hermiticity::Hermitian);
+        }
+
+        // the 2-centre kinds listed as (anti-)hermitian are, component by component
+        let cint_data = water();
+        let intor = cint_data.gen_intor_all();
+        let nao = intor.nao(CintType::Spheric) as usize;
+        for kind in INTEGRAL_KINDS.iter().filter(|kind| kind.ncenter == 2) {
+            let sign = match kind.hermi {
+                Hermiticity::Hermitian => 1.0,
+                Hermiticity::AntiHermitian => -1.0,
+                Hermiticity::NonHermitian => continue,
+            };
+            let mat = intor
+                .intor_kind::<2>(kind, CintType::Spheric, None)
+                .unwrap();
+            for icomp in 0..kind.ncomp {
+                for i in 0..nao {
+                    for j in 0..i {
+                        let (x, y) = (mat[vec![i, j, icomp]], mat[vec![j, i, icomp]]);
+                        assert!(
+                            (x - sign * y).abs() < 1e-10 * (1.0 + x.abs()),
+                            "{}",
+                            kind.name
+                        );
+                    }
+                }
+            }
+        }
+        let eri = intor
+            .intor_kind::<4>(&INT2E, CintType::Spheric, None)
+            .unwrap();
+        for (i, j, k, l) in [(0, 1, 2, 3), (1, 4, 0, 6), (2, 5, 5, 3)] {
+            let x = eri[vec![i, j, k, l]];
+            assert!((x - eri[vec![j, i, k, l]]).abs() < 1e-12);
+            assert!((x - eri[vec![k, l, i, j]]).abs() < 1e-12);
+        }
     }
 }